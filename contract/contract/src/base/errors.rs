@@ -13,7 +13,8 @@ pub enum CrowdfundingError {
     InvalidPoolName = 7,
     InvalidPoolTarget = 8,
     InvalidPoolDeadline = 9,
-    PoolAlreadyExists = 10,
+    // 10 was PoolAlreadyExists, retired because pool IDs are sequential.
+    // Retired codes are never reassigned.
     InvalidPoolState = 11,
     ContractPaused = 12,
     ContractAlreadyPaused = 13,
@@ -51,4 +52,8 @@ pub enum CrowdfundingError {
     PoolAlreadyClosed = 45,
     PoolNotDisbursedOrRefunded = 46,
     InsufficientFees = 47,
+    CampaignNotSuccessful = 48,
+    NothingToWithdraw = 49,
+    CampaignCancelled = 50,
+    CauseNotFound = 53,
 }
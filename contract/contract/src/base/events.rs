@@ -1,7 +1,7 @@
 #![allow(deprecated)]
-use soroban_sdk::{Address, BytesN, Env, String, Symbol};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
 
-use crate::base::types::PoolState;
+use crate::base::types::{FeeRecipient, PoolCapMode, PoolState};
 
 pub fn campaign_created(
     env: &Env,
@@ -15,6 +15,11 @@ pub fn campaign_created(
     env.events().publish(topics, (title, goal, deadline));
 }
 
+pub fn campaign_expiry_settled(env: &Env, settled_bucket: u64, expired: u32) {
+    let topics = (Symbol::new(env, "campaign_expiry_settled"),);
+    env.events().publish(topics, (settled_bucket, expired));
+}
+
 pub fn pool_created(
     env: &Env,
     pool_id: u64,
@@ -34,6 +39,20 @@ pub fn pool_state_updated(env: &Env, pool_id: u64, new_state: PoolState) {
     env.events().publish(topics, new_state);
 }
 
+pub fn pool_cap_mode_updated(env: &Env, pool_id: u64, updated_by: Address, mode: PoolCapMode) {
+    let topics = (
+        Symbol::new(env, "pool_cap_mode_updated"),
+        pool_id,
+        updated_by,
+    );
+    env.events().publish(topics, mode);
+}
+
+pub fn pool_target_reached(env: &Env, pool_id: u64, target_amount: i128, total_raised: i128) {
+    let topics = (Symbol::new(env, "pool_target_reached"), pool_id);
+    env.events().publish(topics, (target_amount, total_raised));
+}
+
 pub fn contract_paused(env: &Env, admin: Address, timestamp: u64) {
     let topics = (Symbol::new(env, "contract_paused"), admin);
     env.events().publish(topics, timestamp);
@@ -49,23 +68,63 @@ pub fn emergency_contact_updated(env: &Env, admin: Address, contact: Address) {
     env.events().publish(topics, contact);
 }
 
-pub fn donation_made(env: &Env, campaign_id: BytesN<32>, contributor: Address, amount: i128) {
+pub fn donation_made(
+    env: &Env,
+    campaign_id: BytesN<32>,
+    contributor: Address,
+    net_amount: i128,
+    fee: i128,
+) {
     let topics = (Symbol::new(env, "donation_made"), campaign_id);
-    env.events().publish(topics, (contributor, amount));
+    env.events().publish(topics, (contributor, net_amount, fee));
 }
 
+pub fn campaign_cancelled(
+    env: &Env,
+    campaign_id: BytesN<32>,
+    cancelled_by: Address,
+    reason: String,
+    timestamp: u64,
+) {
+    let topics = (
+        Symbol::new(env, "campaign_cancelled"),
+        campaign_id,
+        cancelled_by,
+    );
+    env.events().publish(topics, (reason, timestamp));
+}
+
+pub fn donation_refunded(env: &Env, campaign_id: BytesN<32>, donor: Address, amount: i128) {
+    let topics = (Symbol::new(env, "donation_refunded"), campaign_id);
+    env.events().publish(topics, (donor, amount));
+}
+
+pub fn campaign_funds_withdrawn(
+    env: &Env,
+    campaign_id: BytesN<32>,
+    creator: Address,
+    amount: i128,
+    total_withdrawn: i128,
+) {
+    let topics = (Symbol::new(env, "campaign_funds_withdrawn"), campaign_id);
+    env.events()
+        .publish(topics, (creator, amount, total_withdrawn));
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn contribution(
     env: &Env,
     pool_id: u64,
     contributor: Address,
     asset: Address,
-    amount: i128,
+    net_amount: i128,
+    fee: i128,
     timestamp: u64,
     is_private: bool,
 ) {
     let topics = (Symbol::new(env, "contribution"), pool_id, contributor);
     env.events()
-        .publish(topics, (asset, amount, timestamp, is_private));
+        .publish(topics, (asset, net_amount, fee, timestamp, is_private));
 }
 
 pub fn emergency_withdraw_requested(
@@ -89,14 +148,93 @@ pub fn crowdfunding_token_set(env: &Env, admin: Address, token: Address) {
     env.events().publish(topics, token);
 }
 
+pub fn token_added(env: &Env, admin: Address, token: Address) {
+    let topics = (Symbol::new(env, "token_added"), admin);
+    env.events().publish(topics, token);
+}
+
+pub fn token_removed(env: &Env, admin: Address, token: Address) {
+    let topics = (Symbol::new(env, "token_removed"), admin);
+    env.events().publish(topics, token);
+}
+
 pub fn creation_fee_set(env: &Env, admin: Address, fee: i128) {
     let topics = (Symbol::new(env, "creation_fee_set"), admin);
     env.events().publish(topics, fee);
 }
 
-pub fn creation_fee_paid(env: &Env, creator: Address, amount: i128) {
+pub fn platform_fee_set(env: &Env, admin: Address, fee_bps: u32) {
+    let topics = (Symbol::new(env, "platform_fee_set"), admin);
+    env.events().publish(topics, fee_bps);
+}
+
+pub fn platform_fee_caps_set(
+    env: &Env,
+    admin: Address,
+    token: Address,
+    min_fee: i128,
+    max_fee: i128,
+) {
+    let topics = (Symbol::new(env, "platform_fee_caps_set"), admin, token);
+    env.events().publish(topics, (min_fee, max_fee));
+}
+
+pub fn fee_split_set(env: &Env, admin: Address, recipients: Vec<FeeRecipient>) {
+    let topics = (Symbol::new(env, "fee_split_set"), admin);
+    env.events().publish(topics, recipients);
+}
+
+pub fn platform_fees_distributed(
+    env: &Env,
+    caller: Address,
+    token: Address,
+    amount: i128,
+    timestamp: u64,
+) {
+    let topics = (Symbol::new(env, "platform_fees_distributed"), token);
+    env.events().publish(topics, (caller, amount, timestamp));
+}
+
+pub fn cause_verified(
+    env: &Env,
+    verifier: Address,
+    cause: Address,
+    expires_at: Option<u64>,
+    category: String,
+) {
+    let topics = (Symbol::new(env, "cause_verified"), cause);
+    env.events()
+        .publish(topics, (verifier, expires_at, category));
+}
+
+pub fn cause_revoked(env: &Env, revoked_by: Address, cause: Address, timestamp: u64) {
+    let topics = (Symbol::new(env, "cause_revoked"), cause);
+    env.events().publish(topics, (revoked_by, timestamp));
+}
+
+pub fn cause_verifier_added(env: &Env, admin: Address, verifier: Address) {
+    let topics = (Symbol::new(env, "cause_verifier_added"), admin);
+    env.events().publish(topics, verifier);
+}
+
+pub fn cause_verifier_removed(env: &Env, admin: Address, verifier: Address) {
+    let topics = (Symbol::new(env, "cause_verifier_removed"), admin);
+    env.events().publish(topics, verifier);
+}
+
+pub fn required_attestations_updated(env: &Env, admin: Address, required: u32) {
+    let topics = (Symbol::new(env, "required_attestations_updated"), admin);
+    env.events().publish(topics, required);
+}
+
+pub fn creation_fee_paid(env: &Env, creator: Address, amount: i128, discount: i128) {
     let topics = (Symbol::new(env, "creation_fee_paid"), creator);
-    env.events().publish(topics, amount);
+    env.events().publish(topics, (amount, discount));
+}
+
+pub fn verified_cause_discount_set(env: &Env, admin: Address, discount_bps: u32) {
+    let topics = (Symbol::new(env, "verified_cause_discount_set"), admin);
+    env.events().publish(topics, discount_bps);
 }
 
 pub fn refund(
@@ -116,7 +254,87 @@ pub fn pool_closed(env: &Env, pool_id: u64, closed_by: Address, timestamp: u64)
     env.events().publish(topics, timestamp);
 }
 
-pub fn platform_fees_withdrawn(env: &Env, admin: Address, amount: i128) {
-    let topics = (Symbol::new(env, "platform_fees_withdrawn"), admin);
-    env.events().publish(topics, amount);
+pub fn platform_fees_withdrawn(
+    env: &Env,
+    admin: Address,
+    token: Address,
+    to: Address,
+    amount: i128,
+) {
+    let topics = (Symbol::new(env, "platform_fees_withdrawn"), admin, token);
+    env.events().publish(topics, (to, amount));
+}
+
+pub fn disbursement_proposed(
+    env: &Env,
+    pool_id: u64,
+    disbursement_id: u64,
+    proposer: Address,
+    recipient: Address,
+    asset: Address,
+    amount: i128,
+) {
+    let topics = (
+        Symbol::new(env, "disbursement_proposed"),
+        pool_id,
+        disbursement_id,
+    );
+    env.events()
+        .publish(topics, (proposer, recipient, asset, amount));
+}
+
+pub fn disbursement_approved(
+    env: &Env,
+    pool_id: u64,
+    disbursement_id: u64,
+    signer: Address,
+    approval_count: u32,
+) {
+    let topics = (
+        Symbol::new(env, "disbursement_approved"),
+        pool_id,
+        disbursement_id,
+    );
+    env.events().publish(topics, (signer, approval_count));
+}
+
+pub fn disbursement_executed(
+    env: &Env,
+    pool_id: u64,
+    disbursement_id: u64,
+    recipient: Address,
+    asset: Address,
+    amount: i128,
+) {
+    let topics = (
+        Symbol::new(env, "disbursement_executed"),
+        pool_id,
+        disbursement_id,
+    );
+    env.events().publish(topics, (recipient, asset, amount));
+}
+
+pub fn signer_added(env: &Env, pool_id: u64, signer: Address, signer_count: u32) {
+    let topics = (Symbol::new(env, "signer_added"), pool_id);
+    env.events().publish(topics, (signer, signer_count));
+}
+
+pub fn signer_removed(env: &Env, pool_id: u64, signer: Address, signer_count: u32) {
+    let topics = (Symbol::new(env, "signer_removed"), pool_id);
+    env.events().publish(topics, (signer, signer_count));
+}
+
+pub fn required_signatures_updated(env: &Env, pool_id: u64, old_required: u32, new_required: u32) {
+    let topics = (Symbol::new(env, "required_signatures_updated"), pool_id);
+    env.events().publish(topics, (old_required, new_required));
+}
+
+pub fn storage_migrated(env: &Env, admin: Address, entries: u32) {
+    let topics = (Symbol::new(env, "storage_migrated"), admin);
+    env.events().publish(topics, entries);
+}
+
+pub fn ttl_config_set(env: &Env, admin: Address, threshold: u32, extend_to: u32) {
+    let topics = (Symbol::new(env, "ttl_config_set"), admin);
+    env.events().publish(topics, (threshold, extend_to));
 }
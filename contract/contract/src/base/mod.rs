@@ -1,3 +1,5 @@
 pub mod errors;
 pub mod events;
+pub mod state_machine;
+pub mod storage;
 pub mod types;
@@ -0,0 +1,204 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::base::{
+    errors::CrowdfundingError,
+    events, storage,
+    types::{PoolState, PoolStateChange, StorageKey},
+};
+
+const ALL_STATES: [PoolState; 6] = [
+    PoolState::Active,
+    PoolState::Paused,
+    PoolState::Completed,
+    PoolState::Cancelled,
+    PoolState::Disbursed,
+    PoolState::Closed,
+];
+
+/// Every `(from, to)` pair a pool may move through during its lifecycle.
+const TRANSITIONS: [(PoolState, PoolState); 11] = [
+    (PoolState::Active, PoolState::Paused),
+    (PoolState::Active, PoolState::Completed),
+    (PoolState::Active, PoolState::Cancelled),
+    (PoolState::Active, PoolState::Disbursed),
+    (PoolState::Paused, PoolState::Active),
+    (PoolState::Paused, PoolState::Completed),
+    (PoolState::Paused, PoolState::Cancelled),
+    (PoolState::Completed, PoolState::Cancelled),
+    (PoolState::Completed, PoolState::Disbursed),
+    (PoolState::Disbursed, PoolState::Closed),
+    (PoolState::Cancelled, PoolState::Closed),
+];
+
+pub fn is_allowed(from: &PoolState, to: &PoolState) -> bool {
+    TRANSITIONS
+        .iter()
+        .any(|(allowed_from, allowed_to)| allowed_from == from && allowed_to == to)
+}
+
+/// Checks a transition against the table, mapping each rejection to the
+/// error that best explains it.
+pub fn validate_transition(from: &PoolState, to: &PoolState) -> Result<(), CrowdfundingError> {
+    if is_allowed(from, to) {
+        return Ok(());
+    }
+
+    match (from, to) {
+        (PoolState::Closed, _) => Err(CrowdfundingError::PoolAlreadyClosed),
+        (PoolState::Disbursed, _) => Err(CrowdfundingError::PoolAlreadyDisbursed),
+        (_, PoolState::Closed) => Err(CrowdfundingError::PoolNotDisbursedOrRefunded),
+        _ => Err(CrowdfundingError::InvalidPoolState),
+    }
+}
+
+/// Returns the states `update_pool_state` may move a pool to from `from`.
+pub fn next_manual_states(env: &Env, from: &PoolState) -> Vec<PoolState> {
+    let mut states = Vec::new(env);
+    for to in ALL_STATES.iter() {
+        if validate_manual_transition(from, to).is_ok() {
+            states.push_back(to.clone());
+        }
+    }
+    states
+}
+
+/// Like [`validate_transition`], for changes requested through
+/// `update_pool_state`. `Disbursed` is never a valid manual target: pools only
+/// reach it by executing an approved disbursement, so no caller is authorized
+/// to set it directly.
+pub fn validate_manual_transition(
+    from: &PoolState,
+    to: &PoolState,
+) -> Result<(), CrowdfundingError> {
+    if *to == PoolState::Disbursed && *from != PoolState::Disbursed {
+        return Err(CrowdfundingError::Unauthorized);
+    }
+    validate_transition(from, to)
+}
+
+/// Refunds stay open until the pool's funds have been paid out or the pool
+/// has been closed.
+pub fn validate_refund(state: &PoolState) -> Result<(), CrowdfundingError> {
+    match state {
+        PoolState::Disbursed => Err(CrowdfundingError::PoolAlreadyDisbursed),
+        PoolState::Closed => Err(CrowdfundingError::PoolAlreadyClosed),
+        _ => Ok(()),
+    }
+}
+
+pub fn get_state(env: &Env, pool_id: u64) -> PoolState {
+    storage::get(env, &StorageKey::PoolState(pool_id)).unwrap_or(PoolState::Active)
+}
+
+pub fn get_history(env: &Env, pool_id: u64) -> Vec<PoolStateChange> {
+    storage::get(env, &StorageKey::PoolStateHistory(pool_id)).unwrap_or(Vec::new(env))
+}
+
+/// Validates and applies a transition, recording it in the pool's history.
+pub fn transition(
+    env: &Env,
+    pool_id: u64,
+    to: PoolState,
+    actor: Address,
+) -> Result<(), CrowdfundingError> {
+    let from = get_state(env, pool_id);
+    validate_transition(&from, &to)?;
+
+    storage::set(env, &StorageKey::PoolState(pool_id), &to);
+
+    let mut history = get_history(env, pool_id);
+    history.push_back(PoolStateChange {
+        from,
+        to: to.clone(),
+        changed_by: actor,
+        changed_at: env.ledger().timestamp(),
+    });
+    storage::set(env, &StorageKey::PoolStateHistory(pool_id), &history);
+
+    events::pool_state_updated(env, pool_id, to);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allowed_transitions_pass_validation() {
+        for (from, to) in TRANSITIONS.iter() {
+            assert_eq!(validate_transition(from, to), Ok(()));
+        }
+    }
+
+    #[test]
+    fn rejected_transitions_map_to_specific_errors() {
+        assert_eq!(
+            validate_transition(&PoolState::Disbursed, &PoolState::Active),
+            Err(CrowdfundingError::PoolAlreadyDisbursed)
+        );
+        assert_eq!(
+            validate_transition(&PoolState::Closed, &PoolState::Active),
+            Err(CrowdfundingError::PoolAlreadyClosed)
+        );
+        assert_eq!(
+            validate_transition(&PoolState::Active, &PoolState::Closed),
+            Err(CrowdfundingError::PoolNotDisbursedOrRefunded)
+        );
+        assert_eq!(
+            validate_transition(&PoolState::Cancelled, &PoolState::Active),
+            Err(CrowdfundingError::InvalidPoolState)
+        );
+        assert_eq!(
+            validate_transition(&PoolState::Active, &PoolState::Active),
+            Err(CrowdfundingError::InvalidPoolState)
+        );
+    }
+
+    #[test]
+    fn manual_transition_cannot_target_disbursed() {
+        assert_eq!(
+            validate_manual_transition(&PoolState::Active, &PoolState::Disbursed),
+            Err(CrowdfundingError::Unauthorized)
+        );
+        assert_eq!(
+            validate_manual_transition(&PoolState::Disbursed, &PoolState::Disbursed),
+            Err(CrowdfundingError::PoolAlreadyDisbursed)
+        );
+        assert_eq!(
+            validate_manual_transition(&PoolState::Active, &PoolState::Paused),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn refunds_blocked_after_payout_or_close() {
+        assert_eq!(validate_refund(&PoolState::Active), Ok(()));
+        assert_eq!(validate_refund(&PoolState::Cancelled), Ok(()));
+        assert_eq!(
+            validate_refund(&PoolState::Disbursed),
+            Err(CrowdfundingError::PoolAlreadyDisbursed)
+        );
+        assert_eq!(
+            validate_refund(&PoolState::Closed),
+            Err(CrowdfundingError::PoolAlreadyClosed)
+        );
+    }
+
+    #[test]
+    fn next_manual_states_exclude_disbursed() {
+        let env = Env::default();
+        let from_active = next_manual_states(&env, &PoolState::Active);
+        assert_eq!(from_active.len(), 3);
+        assert!(!from_active.contains(PoolState::Disbursed));
+        assert!(next_manual_states(&env, &PoolState::Closed).is_empty());
+        assert_eq!(
+            next_manual_states(&env, &PoolState::Completed),
+            Vec::from_array(&env, [PoolState::Cancelled])
+        );
+        assert_eq!(
+            next_manual_states(&env, &PoolState::Disbursed),
+            Vec::from_array(&env, [PoolState::Closed])
+        );
+    }
+}
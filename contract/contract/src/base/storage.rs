@@ -0,0 +1,235 @@
+//! Storage layout for the contract.
+//!
+//! Contract-wide configuration (admin, fee settings, token registry, pause
+//! flag) lives in instance storage, which is loaded on every invocation.
+//! Per-entity records (campaigns, pools, contributions, donor flags, metrics,
+//! disbursements, cause verifications) live in persistent storage so that the
+//! instance entry stays small no matter how many donors a campaign has.
+//!
+//! Earlier deployments kept everything in instance storage. Reads of
+//! per-entity keys fall back to the legacy instance entry, and writes move the
+//! value into persistent storage, so existing data migrates lazily. `migrate`
+//! moves a single entry eagerly.
+//!
+//! Every read or write of a persistent entry extends its TTL, and every
+//! write extends the instance TTL, according to the stored `TtlConfig`.
+use soroban_sdk::{Address, Env, IntoVal, Symbol, TryFromVal, Val};
+
+use crate::base::types::{PoolContribution, StorageKey, TtlConfig};
+
+/// Returns `true` for keys that hold per-entity data.
+pub fn is_persistent(key: &StorageKey) -> bool {
+    matches!(
+        key,
+        StorageKey::Campaign(_)
+            | StorageKey::CampaignIndex(_)
+            | StorageKey::CampaignDeadlines(_)
+            | StorageKey::CreatorIndexCounts(_)
+            | StorageKey::CreatorCampaign(_, _)
+            | StorageKey::CreatorPool(_, _)
+            | StorageKey::CampaignMetrics(_)
+            | StorageKey::CampaignDonor(_, _)
+            | StorageKey::CampaignCancelled(_)
+            | StorageKey::CampaignWithdrawn(_)
+            | StorageKey::Contribution(_, _)
+            | StorageKey::Pool(_)
+            | StorageKey::PoolCreator(_)
+            | StorageKey::PoolState(_)
+            | StorageKey::PoolStateHistory(_)
+            | StorageKey::PoolCapMode(_)
+            | StorageKey::PoolMetrics(_)
+            | StorageKey::PoolMetadata(_)
+            | StorageKey::PoolContribution(_, _, _)
+            | StorageKey::PoolContributorAssets(_, _)
+            | StorageKey::PoolAssetTotals(_)
+            | StorageKey::PoolAcceptedAssets(_)
+            | StorageKey::PoolVerifiedRecipientsOnly(_)
+            | StorageKey::MultiSigConfig(_)
+            | StorageKey::DisbursementRequest(_, _)
+            | StorageKey::NextDisbursementId(_)
+            | StorageKey::PoolPayoutExecuted(_)
+            | StorageKey::VerifiedCause(_)
+            | StorageKey::CauseAttestations(_)
+    )
+}
+
+pub fn get<V: TryFromVal<Env, Val>>(env: &Env, key: &StorageKey) -> Option<V> {
+    if !is_persistent(key) {
+        return env.storage().instance().get(key);
+    }
+    match env.storage().persistent().get(key) {
+        Some(value) => {
+            extend_entry(env, key, &ttl_config(env));
+            Some(value)
+        }
+        None => legacy_get(env, key),
+    }
+}
+
+pub fn has(env: &Env, key: &StorageKey) -> bool {
+    if !is_persistent(key) {
+        return env.storage().instance().has(key);
+    }
+    env.storage().persistent().has(key) || legacy_has(env, key)
+}
+
+pub fn set<V: IntoVal<Env, Val>>(env: &Env, key: &StorageKey, value: &V) {
+    let config = ttl_config(env);
+    if is_persistent(key) {
+        env.storage().persistent().set(key, value);
+        extend_entry(env, key, &config);
+        legacy_remove(env, key);
+    } else {
+        env.storage().instance().set(key, value);
+    }
+    extend_instance_with(env, &config);
+}
+
+pub fn remove(env: &Env, key: &StorageKey) {
+    if is_persistent(key) {
+        env.storage().persistent().remove(key);
+        legacy_remove(env, key);
+    } else {
+        env.storage().instance().remove(key);
+    }
+    extend_instance_with(env, &ttl_config(env));
+}
+
+pub fn ttl_config(env: &Env) -> TtlConfig {
+    env.storage()
+        .instance()
+        .get(&StorageKey::TtlConfig)
+        .unwrap_or_default()
+}
+
+/// Extends the TTL of a persistent entry if it exists.
+///
+/// Returns `true` if the entry was found.
+pub fn extend(env: &Env, key: &StorageKey) -> bool {
+    if !is_persistent(key) || !env.storage().persistent().has(key) {
+        return false;
+    }
+    extend_entry(env, key, &ttl_config(env));
+    true
+}
+
+pub fn extend_instance(env: &Env) {
+    extend_instance_with(env, &ttl_config(env));
+}
+
+fn extend_entry(env: &Env, key: &StorageKey, config: &TtlConfig) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, config.threshold, config.extend_to);
+}
+
+fn extend_instance_with(env: &Env, config: &TtlConfig) {
+    env.storage()
+        .instance()
+        .extend_ttl(config.threshold, config.extend_to);
+}
+
+/// Moves a legacy instance entry into persistent storage.
+///
+/// Returns `true` if an entry was moved.
+pub fn migrate(env: &Env, key: &StorageKey) -> bool {
+    if !is_persistent(key) {
+        return false;
+    }
+    match legacy_get::<Val>(env, key) {
+        Some(value) => {
+            set(env, key, &value);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Reads a pool contribution stored under the single-asset key layout, which
+/// had no asset component. The record itself names the asset it was made in.
+pub fn legacy_pool_contribution(
+    env: &Env,
+    pool_id: u64,
+    contributor: &Address,
+) -> Option<PoolContribution> {
+    env.storage()
+        .instance()
+        .get(&legacy_pool_contribution_key(env, pool_id, contributor))
+}
+
+pub fn remove_legacy_pool_contribution(env: &Env, pool_id: u64, contributor: &Address) {
+    env.storage()
+        .instance()
+        .remove(&legacy_pool_contribution_key(env, pool_id, contributor));
+}
+
+/// Returns `true` if `cause` was verified under the old layout, where the
+/// verification was a bare `bool`.
+pub fn legacy_cause_verified(env: &Env, cause: &Address) -> bool {
+    env.storage()
+        .instance()
+        .get::<_, Val>(&StorageKey::VerifiedCause(cause.clone()))
+        .and_then(|value| bool::try_from_val(env, &value).ok())
+        .unwrap_or(false)
+}
+
+/// Removes and returns the platform fee balance kept before fees were tracked
+/// per token. All of it was collected in the crowdfunding token.
+pub fn take_legacy_platform_fees(env: &Env) -> Option<i128> {
+    // Encodes the same way the old unit `StorageKey::PlatformFees` did
+    let key = (Symbol::new(env, "PlatformFees"),);
+    let instance = env.storage().instance();
+    let fees = instance.get(&key);
+    if fees.is_some() {
+        instance.remove(&key);
+    }
+    fees
+}
+
+// Encodes the same way the old `StorageKey::PoolContribution(u64, Address)`
+// variant did.
+fn legacy_pool_contribution_key(
+    env: &Env,
+    pool_id: u64,
+    contributor: &Address,
+) -> (Symbol, u64, Address) {
+    (
+        Symbol::new(env, "PoolContribution"),
+        pool_id,
+        contributor.clone(),
+    )
+}
+
+// Campaign records were stored under a bare `(id,)` tuple before
+// `StorageKey::Campaign` existed. Pool contributions, cause verifications and
+// the platform fee balance changed shape too and have helpers of their own.
+// Deadline buckets never lived in instance storage, so they skip the lookup;
+// the remaining keys kept their shape.
+fn legacy_get<V: TryFromVal<Env, Val>>(env: &Env, key: &StorageKey) -> Option<V> {
+    let instance = env.storage().instance();
+    match key {
+        StorageKey::Campaign(id) => instance.get(&(id.clone(),)),
+        StorageKey::VerifiedCause(_) | StorageKey::CampaignDeadlines(_) => None,
+        _ => instance.get(key),
+    }
+}
+
+fn legacy_has(env: &Env, key: &StorageKey) -> bool {
+    let instance = env.storage().instance();
+    match key {
+        StorageKey::Campaign(id) => instance.has(&(id.clone(),)),
+        StorageKey::CampaignDeadlines(_) => false,
+        _ => instance.has(key),
+    }
+}
+
+fn legacy_remove(env: &Env, key: &StorageKey) {
+    if !legacy_has(env, key) {
+        return;
+    }
+    let instance = env.storage().instance();
+    match key {
+        StorageKey::Campaign(id) => instance.remove(&(id.clone(),)),
+        _ => instance.remove(key),
+    }
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Map, String, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -12,6 +12,92 @@ pub struct CampaignDetails {
     pub token_address: Address,
 }
 
+/// Platform fee taken from each donation and pool contribution, in basis
+/// points of the gross amount. Tokens differ in decimals, so the bounds on
+/// the result are set per token in `caps`; tokens without an entry are
+/// uncapped.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformFeeConfig {
+    pub fee_bps: u32,
+    pub caps: Map<Address, FeeCaps>,
+}
+
+/// Bounds on the platform fee charged in one token, in that token's own
+/// units. A `max_fee` of 0 leaves the fee uncapped.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCaps {
+    pub min_fee: i128,
+    pub max_fee: i128,
+}
+
+/// TTL policy applied to stored records, in ledgers. Whenever an entry is
+/// touched and its remaining TTL is below `threshold`, it is extended to
+/// `extend_to`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlConfig {
+    pub threshold: u32,
+    pub extend_to: u32,
+}
+
+impl Default for TtlConfig {
+    fn default() -> Self {
+        Self {
+            threshold: DEFAULT_TTL_THRESHOLD,
+            extend_to: DEFAULT_TTL_EXTEND_TO,
+        }
+    }
+}
+
+/// Details recorded when a cause is verified. A verification with an
+/// `expires_at` stops counting once the ledger reaches that timestamp.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CauseVerification {
+    pub verifier: Address,
+    pub verified_at: u64,
+    pub expires_at: Option<u64>,
+    pub registration_ref: BytesN<32>,
+    pub category: String,
+}
+
+/// A verifier's attestation that a cause is genuine. It only counts while
+/// the attester has been a verifier since `attested_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CauseAttestation {
+    pub attester: Address,
+    pub attested_at: u64,
+}
+
+/// One entry of the platform fee split: `recipient` receives `share_bps`
+/// basis points of every distribution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRecipient {
+    pub recipient: Address,
+    pub share_bps: u32,
+}
+
+/// A token the platform trusts, with the display data cached at registration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenInfo {
+    pub address: Address,
+    pub decimals: u32,
+    pub symbol: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignCancellation {
+    pub cancelled_by: Address,
+    pub reason: String,
+    pub cancelled_at: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Contribution {
@@ -50,6 +136,24 @@ pub struct PoolMetadata {
 pub const MAX_DESCRIPTION_LENGTH: u32 = 500;
 pub const MAX_URL_LENGTH: u32 = 200;
 pub const MAX_HASH_LENGTH: u32 = 100;
+/// Basis-point denominator; the platform fee can never exceed 100%.
+pub const MAX_FEE_BPS: u32 = 10_000;
+/// Upper bound on the number of entries returned by a paginated query, and
+/// the page size used when a query asks for a limit of 0.
+pub const MAX_PAGE_SIZE: u32 = 100;
+/// Width in seconds of the buckets campaign deadlines are grouped into.
+pub const CAMPAIGN_DEADLINE_BUCKET: u64 = 86_400;
+/// Elapsed deadline buckets settled as a side effect of creating a campaign.
+pub const AUTO_SETTLED_DEADLINE_BUCKETS: u32 = 7;
+/// Unsettled deadline buckets a campaign count query reads at most. Campaigns
+/// expiring further past the last settlement keep counting as active, so
+/// `sweep_expired_campaigns` has to be called at least this often (in days)
+/// when no campaigns are being created.
+pub const MAX_UNSETTLED_DEADLINE_BUCKETS: u64 = 30;
+/// Ledgers closed per day at a five second close time.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+pub const DEFAULT_TTL_THRESHOLD: u32 = 7 * DAY_IN_LEDGERS;
+pub const DEFAULT_TTL_EXTEND_TO: u32 = 30 * DAY_IN_LEDGERS;
 
 impl PoolConfig {
     /// Validate pool configuration according to Nevo invariants.
@@ -121,6 +225,28 @@ pub enum PoolState {
     Closed = 5,
 }
 
+/// How `contribute` treats amounts that would push a pool past its target.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum PoolCapMode {
+    /// The final contribution may overshoot the target.
+    Soft = 0,
+    /// Contributions that would overshoot the target are rejected.
+    Reject = 1,
+    /// Contributions are trimmed to the amount still needed.
+    Trim = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoolStateChange {
+    pub from: PoolState,
+    pub to: PoolState,
+    pub changed_by: Address,
+    pub changed_at: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CampaignMetrics {
@@ -152,6 +278,8 @@ impl CampaignMetrics {
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PoolMetrics {
+    /// Amount raised in the pool's target asset, the first asset it accepts.
+    /// Contributions in other assets are tracked in `PoolAssetTotals` only.
     pub total_raised: i128,
     pub contributor_count: u32,
     pub last_donation_at: u64,
@@ -174,11 +302,66 @@ impl PoolMetrics {
     }
 }
 
+/// Everything a listing needs to render a campaign in one read.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignSummary {
+    pub campaign: CampaignDetails,
+    pub status: CampaignLifecycleStatus,
+    pub metrics: CampaignMetrics,
+}
+
+/// One page of campaign summaries. `next_cursor` is the registry index to
+/// resume from, or `None` once the end of the registry has been reached.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignPage {
+    pub items: Vec<CampaignSummary>,
+    pub next_cursor: Option<u32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolSummary {
+    pub pool_id: u64,
+    pub config: PoolConfig,
+    pub state: PoolState,
+    pub metrics: PoolMetrics,
+}
+
+/// Number of campaigns and pools indexed under a creator.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct CreatorIndexCounts {
+    pub campaigns: u32,
+    pub pools: u32,
+}
+
+/// Deadline buckets folded into the expired campaign count so far.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignExpiry {
+    /// Every bucket before this one has been settled.
+    pub settled_bucket: u64,
+    /// Campaigns whose deadline falls in a settled bucket.
+    pub expired: u32,
+}
+
+/// One page of pool summaries. `next_cursor` is the pool ID to resume from,
+/// or `None` once every pool has been visited.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolPage {
+    pub items: Vec<PoolSummary>,
+    pub next_cursor: Option<u64>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DisbursementRequest {
     pub pool_id: u64,
     pub amount: i128,
+    pub asset: Address,
     pub recipient: Address,
     pub approvals: Vec<Address>,
     pub created_at: u64,
@@ -207,14 +390,31 @@ pub struct PoolContribution {
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum StorageKey {
+    Campaign(BytesN<32>),
     Pool(u64),
+    PoolCreator(u64),
     PoolState(u64),
+    PoolStateHistory(u64),
+    PoolCapMode(u64),
     PoolMetrics(u64),
+    // Pre-registry campaign list, drained by `migrate_campaign_registry`
     AllCampaigns,
+    CampaignCount,
+    CampaignIndex(u32),
+    // Number of campaigns per deadline within one `CAMPAIGN_DEADLINE_BUCKET`
+    CampaignDeadlines(u64),
+    CampaignExpiry,
+    CreatorIndexCounts(Address),
+    CreatorCampaign(Address, u32),
+    CreatorPool(Address, u32),
     CampaignMetrics(BytesN<32>),
     CampaignDonor(BytesN<32>, Address),
     Contribution(BytesN<32>, Address),
-    PoolContribution(u64, Address),
+    PoolContribution(u64, Address, Address),
+    PoolContributorAssets(u64, Address),
+    PoolAssetTotals(u64),
+    PoolAcceptedAssets(u64),
+    PoolVerifiedRecipientsOnly(u64),
 
     NextPoolId,
     IsPaused,
@@ -223,14 +423,28 @@ pub enum StorageKey {
     DisbursementRequest(u64, u64),
     PoolMetadata(u64),
     NextDisbursementId(u64),
+    // Set once any disbursement has been paid out of the pool
+    PoolPayoutExecuted(u64),
     EmergencyWithdrawal,
     CrowdfundingToken,
     CreationFee,
     VerifiedCause(Address),
-    PlatformFees,
+    PlatformFees(Address),
+    PlatformFeeTokens,
+    FeeSplit,
+    VerifiedCauseFeeDiscount,
+    // Map of each cause verifier to the time it was added
+    CauseVerifiers,
+    RequiredAttestations,
+    CauseAttestations(Address),
+    PlatformFeeConfig,
     GlobalTotalRaised,
     CampaignCancelled(BytesN<32>),
+    CampaignWithdrawn(BytesN<32>),
     EmergencyContact,
+    SupportedTokens,
+    TokenInfo(Address),
+    TtlConfig,
 }
 
 #[cfg(test)]
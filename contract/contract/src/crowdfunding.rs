@@ -1,14 +1,19 @@
 #![allow(deprecated)]
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
+#![allow(clippy::too_many_arguments)]
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Map, String, Vec};
 
 use crate::base::{
     errors::CrowdfundingError,
-    events,
+    events, state_machine, storage,
     types::{
-        CampaignDetails, CampaignLifecycleStatus, CampaignMetrics, Contribution,
-        EmergencyWithdrawal, MultiSigConfig, PoolConfig, PoolContribution, PoolMetadata,
-        PoolMetrics, PoolState, StorageKey, MAX_DESCRIPTION_LENGTH, MAX_HASH_LENGTH,
-        MAX_URL_LENGTH,
+        CampaignCancellation, CampaignDetails, CampaignExpiry, CampaignLifecycleStatus,
+        CampaignMetrics, CampaignPage, CampaignSummary, CauseAttestation, CauseVerification,
+        Contribution, CreatorIndexCounts, DisbursementRequest, EmergencyWithdrawal, FeeCaps,
+        FeeRecipient, MultiSigConfig, PlatformFeeConfig, PoolCapMode, PoolConfig, PoolContribution,
+        PoolMetadata, PoolMetrics, PoolPage, PoolState, PoolStateChange, PoolSummary, StorageKey,
+        TokenInfo, TtlConfig, AUTO_SETTLED_DEADLINE_BUCKETS, CAMPAIGN_DEADLINE_BUCKET,
+        MAX_DESCRIPTION_LENGTH, MAX_FEE_BPS, MAX_HASH_LENGTH, MAX_PAGE_SIZE,
+        MAX_UNSETTLED_DEADLINE_BUCKETS, MAX_URL_LENGTH,
     },
 };
 use crate::interfaces::crowdfunding::CrowdfundingTrait;
@@ -17,7 +22,6 @@ use crate::interfaces::crowdfunding::CrowdfundingTrait;
 pub struct CrowdfundingContract;
 
 #[contractimpl]
-#[allow(clippy::too_many_arguments)]
 impl CrowdfundingTrait for CrowdfundingContract {
     fn create_campaign(
         env: Env,
@@ -26,7 +30,7 @@ impl CrowdfundingTrait for CrowdfundingContract {
         creator: Address,
         goal: i128,
         deadline: u64,
-        _token_address: Address,
+        token_address: Address,
     ) -> Result<(), CrowdfundingError> {
         if Self::is_paused(env.clone()) {
             return Err(CrowdfundingError::ContractPaused);
@@ -45,42 +49,19 @@ impl CrowdfundingTrait for CrowdfundingContract {
             return Err(CrowdfundingError::InvalidDeadline);
         }
 
-        let token_key = StorageKey::CrowdfundingToken;
-        if !env.storage().instance().has(&token_key) {
+        if !storage::has(&env, &StorageKey::CrowdfundingToken) {
             return Err(CrowdfundingError::NotInitialized);
         }
-        let token_address: Address = env.storage().instance().get(&token_key).unwrap();
 
-        let fee_key = StorageKey::CreationFee;
-        let creation_fee: i128 = env.storage().instance().get(&fee_key).unwrap_or(0);
-
-        if creation_fee > 0 {
-            use soroban_sdk::token;
-            let token_client = token::Client::new(&env, &token_address);
-
-            let balance = token_client.balance(&creator);
-            if balance < creation_fee {
-                return Err(CrowdfundingError::InsufficientBalance);
-            }
-
-            token_client.transfer(&creator, env.current_contract_address(), &creation_fee);
-
-            // Track platform fees
-            let platform_fees_key = StorageKey::PlatformFees;
-            let current_fees: i128 = env
-                .storage()
-                .instance()
-                .get(&platform_fees_key)
-                .unwrap_or(0);
-            env.storage()
-                .instance()
-                .set(&platform_fees_key, &(current_fees + creation_fee));
-
-            events::creation_fee_paid(&env, creator.clone(), creation_fee);
+        // Campaigns raise funds in the requested token, which must be registered
+        if !Self::is_token_supported(env.clone(), token_address.clone()) {
+            return Err(CrowdfundingError::InvalidToken);
         }
 
-        let campaign_key = (id.clone(),);
-        if env.storage().instance().has(&campaign_key) {
+        Self::charge_creation_fee(&env, &creator)?;
+
+        let campaign_key = StorageKey::Campaign(id.clone());
+        if storage::has(&env, &campaign_key) {
             return Err(CrowdfundingError::CampaignAlreadyExists);
         }
 
@@ -94,24 +75,14 @@ impl CrowdfundingTrait for CrowdfundingContract {
             token_address: token_address.clone(),
         };
 
-        env.storage().instance().set(&campaign_key, &campaign);
+        storage::set(&env, &campaign_key, &campaign);
 
         // Initialize metrics
         let metrics_key = StorageKey::CampaignMetrics(id.clone());
-        env.storage()
-            .instance()
-            .set(&metrics_key, &CampaignMetrics::new());
-
-        // Update AllCampaigns list
-        let mut all_campaigns = env
-            .storage()
-            .instance()
-            .get(&StorageKey::AllCampaigns)
-            .unwrap_or(Vec::new(&env));
-        all_campaigns.push_back(id.clone());
-        env.storage()
-            .instance()
-            .set(&StorageKey::AllCampaigns, &all_campaigns);
+        storage::set(&env, &metrics_key, &CampaignMetrics::new());
+
+        Self::index_campaign(&env, &campaign);
+        Self::settle_deadline_buckets(&env, AUTO_SETTLED_DEADLINE_BUCKETS);
 
         events::campaign_created(&env, id, title, creator, goal, deadline);
 
@@ -119,57 +90,262 @@ impl CrowdfundingTrait for CrowdfundingContract {
     }
 
     fn set_crowdfunding_token(env: Env, token: Address) -> Result<(), CrowdfundingError> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::NotInitialized)?;
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
         admin.require_auth();
 
-        env.storage()
-            .instance()
-            .set(&StorageKey::CrowdfundingToken, &token);
+        storage::set(&env, &StorageKey::CrowdfundingToken, &token);
+        if !Self::is_token_supported(env.clone(), token.clone()) {
+            Self::register_token(&env, &token);
+        }
         events::crowdfunding_token_set(&env, admin, token);
         Ok(())
     }
 
     fn get_crowdfunding_token(env: Env) -> Result<Address, CrowdfundingError> {
-        env.storage()
-            .instance()
-            .get(&StorageKey::CrowdfundingToken)
-            .ok_or(CrowdfundingError::NotInitialized)
+        storage::get(&env, &StorageKey::CrowdfundingToken).ok_or(CrowdfundingError::NotInitialized)
+    }
+
+    fn add_supported_token(env: Env, token: Address) -> Result<(), CrowdfundingError> {
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        if Self::is_token_supported(env.clone(), token.clone()) {
+            return Err(CrowdfundingError::InvalidToken);
+        }
+
+        Self::register_token(&env, &token);
+        events::token_added(&env, admin, token);
+        Ok(())
+    }
+
+    fn remove_supported_token(env: Env, token: Address) -> Result<(), CrowdfundingError> {
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        // The crowdfunding token must stay usable for fees and default pools
+        let crowdfunding_token: Option<Address> =
+            storage::get(&env, &StorageKey::CrowdfundingToken);
+        if crowdfunding_token == Some(token.clone()) {
+            return Err(CrowdfundingError::InvalidToken);
+        }
+
+        let mut tokens: Vec<Address> =
+            storage::get(&env, &StorageKey::SupportedTokens).unwrap_or(Vec::new(&env));
+        let index = tokens
+            .first_index_of(&token)
+            .ok_or(CrowdfundingError::InvalidToken)?;
+        tokens.remove(index);
+
+        storage::set(&env, &StorageKey::SupportedTokens, &tokens);
+        storage::remove(&env, &StorageKey::TokenInfo(token.clone()));
+
+        events::token_removed(&env, admin, token);
+        Ok(())
+    }
+
+    fn get_supported_tokens(env: Env) -> Vec<TokenInfo> {
+        let tokens: Vec<Address> =
+            storage::get(&env, &StorageKey::SupportedTokens).unwrap_or(Vec::new(&env));
+
+        let mut infos = Vec::new(&env);
+        for token in tokens.iter() {
+            if let Some(info) = storage::get(&env, &StorageKey::TokenInfo(token)) {
+                infos.push_back(info);
+            }
+        }
+        infos
+    }
+
+    fn is_token_supported(env: Env, token: Address) -> bool {
+        storage::has(&env, &StorageKey::TokenInfo(token))
     }
 
     fn set_creation_fee(env: Env, fee: i128) -> Result<(), CrowdfundingError> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::NotInitialized)?;
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
         admin.require_auth();
 
         if fee < 0 {
             return Err(CrowdfundingError::InvalidFee);
         }
 
-        env.storage().instance().set(&StorageKey::CreationFee, &fee);
+        storage::set(&env, &StorageKey::CreationFee, &fee);
         events::creation_fee_set(&env, admin, fee);
         Ok(())
     }
 
     fn get_creation_fee(env: Env) -> Result<i128, CrowdfundingError> {
-        Ok(env
-            .storage()
-            .instance()
-            .get(&StorageKey::CreationFee)
-            .unwrap_or(0))
+        Ok(storage::get(&env, &StorageKey::CreationFee).unwrap_or(0))
+    }
+
+    fn set_verified_cause_discount(env: Env, discount_bps: u32) -> Result<(), CrowdfundingError> {
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        if discount_bps > MAX_FEE_BPS {
+            return Err(CrowdfundingError::InvalidFee);
+        }
+
+        storage::set(&env, &StorageKey::VerifiedCauseFeeDiscount, &discount_bps);
+        events::verified_cause_discount_set(&env, admin, discount_bps);
+        Ok(())
+    }
+
+    fn get_verified_cause_discount(env: Env) -> u32 {
+        // Verified causes create for free unless the admin sets a smaller discount
+        storage::get(&env, &StorageKey::VerifiedCauseFeeDiscount).unwrap_or(MAX_FEE_BPS)
+    }
+
+    fn set_platform_fee(env: Env, fee_bps: u32) -> Result<(), CrowdfundingError> {
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        if fee_bps > MAX_FEE_BPS {
+            return Err(CrowdfundingError::InvalidFee);
+        }
+
+        let mut config = Self::get_platform_fee(env.clone());
+        config.fee_bps = fee_bps;
+        storage::set(&env, &StorageKey::PlatformFeeConfig, &config);
+        events::platform_fee_set(&env, admin, fee_bps);
+        Ok(())
+    }
+
+    fn set_platform_fee_caps(
+        env: Env,
+        token: Address,
+        min_fee: i128,
+        max_fee: i128,
+    ) -> Result<(), CrowdfundingError> {
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        if min_fee < 0 || max_fee < 0 || (max_fee > 0 && max_fee < min_fee) {
+            return Err(CrowdfundingError::InvalidFee);
+        }
+        if !Self::is_token_supported(env.clone(), token.clone()) {
+            return Err(CrowdfundingError::InvalidToken);
+        }
+
+        // Zero bounds clear the token's caps
+        let mut config = Self::get_platform_fee(env.clone());
+        if min_fee == 0 && max_fee == 0 {
+            config.caps.remove(token.clone());
+        } else {
+            config.caps.set(token.clone(), FeeCaps { min_fee, max_fee });
+        }
+        storage::set(&env, &StorageKey::PlatformFeeConfig, &config);
+        events::platform_fee_caps_set(&env, admin, token, min_fee, max_fee);
+        Ok(())
+    }
+
+    fn get_platform_fee(env: Env) -> PlatformFeeConfig {
+        storage::get(&env, &StorageKey::PlatformFeeConfig).unwrap_or(PlatformFeeConfig {
+            fee_bps: 0,
+            caps: Map::new(&env),
+        })
+    }
+
+    fn get_platform_fees(env: Env, token: Address) -> i128 {
+        storage::get(&env, &StorageKey::PlatformFees(token)).unwrap_or(0)
+    }
+
+    fn get_platform_fee_tokens(env: Env) -> Vec<Address> {
+        storage::get(&env, &StorageKey::PlatformFeeTokens).unwrap_or(Vec::new(&env))
+    }
+
+    fn set_fee_split(env: Env, recipients: Vec<FeeRecipient>) -> Result<(), CrowdfundingError> {
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        // Shares must be positive, name each recipient once and add up to 100%
+        let mut total_bps: u32 = 0;
+        for (i, entry) in recipients.iter().enumerate() {
+            if entry.share_bps == 0 {
+                return Err(CrowdfundingError::InvalidFee);
+            }
+            for other in recipients.iter().skip(i + 1) {
+                if other.recipient == entry.recipient {
+                    return Err(CrowdfundingError::InvalidFee);
+                }
+            }
+            total_bps = total_bps.saturating_add(entry.share_bps);
+        }
+        if total_bps != MAX_FEE_BPS {
+            return Err(CrowdfundingError::InvalidFee);
+        }
+
+        storage::set(&env, &StorageKey::FeeSplit, &recipients);
+        events::fee_split_set(&env, admin, recipients);
+        Ok(())
+    }
+
+    fn get_fee_split(env: Env) -> Vec<FeeRecipient> {
+        storage::get(&env, &StorageKey::FeeSplit).unwrap_or(Vec::new(&env))
+    }
+
+    fn distribute_platform_fees(env: Env, caller: Address) -> Result<(), CrowdfundingError> {
+        if Self::is_paused(env.clone()) {
+            return Err(CrowdfundingError::ContractPaused);
+        }
+        caller.require_auth();
+
+        let recipients = Self::get_fee_split(env.clone());
+        if recipients.is_empty() {
+            return Err(CrowdfundingError::InvalidFee);
+        }
+
+        use soroban_sdk::token;
+        let mut distributed_any = false;
+        for fee_token in Self::get_platform_fee_tokens(env.clone()).iter() {
+            let platform_fees_key = StorageKey::PlatformFees(fee_token.clone());
+            let collected: i128 = storage::get(&env, &platform_fees_key).unwrap_or(0);
+
+            // Rounding dust stays in the ledger for the next distribution
+            let token_client = token::Client::new(&env, &fee_token);
+            let mut paid: i128 = 0;
+            for entry in recipients.iter() {
+                let share = collected * entry.share_bps as i128 / MAX_FEE_BPS as i128;
+                if share > 0 {
+                    token_client.transfer(
+                        &env.current_contract_address(),
+                        &entry.recipient,
+                        &share,
+                    );
+                    paid += share;
+                }
+            }
+            if paid == 0 {
+                continue;
+            }
+
+            storage::set(&env, &platform_fees_key, &(collected - paid));
+            events::platform_fees_distributed(
+                &env,
+                caller.clone(),
+                fee_token,
+                paid,
+                env.ledger().timestamp(),
+            );
+            distributed_any = true;
+        }
+
+        if !distributed_any {
+            return Err(CrowdfundingError::NothingToWithdraw);
+        }
+
+        Ok(())
     }
 
     fn get_global_raised_total(env: Env) -> i128 {
-        env.storage()
-            .instance()
-            .get(&StorageKey::GlobalTotalRaised)
-            .unwrap_or(0)
+        storage::get(&env, &StorageKey::GlobalTotalRaised).unwrap_or(0)
     }
 
     fn get_top_contributor_for_campaign(
@@ -180,77 +356,136 @@ impl CrowdfundingTrait for CrowdfundingContract {
         Self::get_campaign(env.clone(), campaign_id.clone())?;
 
         let metrics_key = StorageKey::CampaignMetrics(campaign_id);
-        let metrics: CampaignMetrics = env
-            .storage()
-            .instance()
-            .get(&metrics_key)
-            .unwrap_or_default();
+        let metrics: CampaignMetrics = storage::get(&env, &metrics_key).unwrap_or_default();
 
         metrics
             .top_contributor
             .ok_or(CrowdfundingError::CampaignNotFound)
     }
 
-    fn get_all_campaigns(env: Env) -> Vec<BytesN<32>> {
-        env.storage()
-            .instance()
-            .get(&StorageKey::AllCampaigns)
-            .unwrap_or(Vec::new(&env))
+    fn get_campaign_count(env: Env) -> u32 {
+        storage::get(&env, &StorageKey::CampaignCount).unwrap_or(0)
+    }
+
+    fn get_campaigns(env: Env, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        let count = Self::get_campaign_count(env.clone());
+        let end = start.saturating_add(Self::page_size(limit)).min(count);
+
+        let mut ids = Vec::new(&env);
+        for index in start..end {
+            if let Some(id) = storage::get(&env, &StorageKey::CampaignIndex(index)) {
+                ids.push_back(id);
+            }
+        }
+        ids
+    }
+
+    fn get_creator_campaign_count(env: Env, creator: Address) -> u32 {
+        Self::creator_index_counts(&env, creator).campaigns
+    }
+
+    fn get_creator_campaigns(
+        env: Env,
+        creator: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<BytesN<32>> {
+        let count = Self::get_creator_campaign_count(env.clone(), creator.clone());
+        let end = start.saturating_add(Self::page_size(limit)).min(count);
+
+        let mut ids = Vec::new(&env);
+        for index in start..end {
+            if let Some(id) =
+                storage::get(&env, &StorageKey::CreatorCampaign(creator.clone(), index))
+            {
+                ids.push_back(id);
+            }
+        }
+        ids
+    }
+
+    fn list_campaigns(
+        env: Env,
+        cursor: u32,
+        limit: u32,
+        status: Option<CampaignLifecycleStatus>,
+    ) -> CampaignPage {
+        let count = Self::get_campaign_count(env.clone());
+        let end = cursor.saturating_add(Self::page_size(limit)).min(count);
+
+        // The filter is applied to the scanned window, so a page may hold
+        // fewer than `limit` items while more remain after `next_cursor`.
+        let mut items = Vec::new(&env);
+        for index in cursor..end {
+            let summary = storage::get(&env, &StorageKey::CampaignIndex(index))
+                .and_then(|id| Self::campaign_summary(&env, id));
+            if let Some(summary) = summary {
+                if status.is_none() || status == Some(summary.status.clone()) {
+                    items.push_back(summary);
+                }
+            }
+        }
+
+        CampaignPage {
+            items,
+            next_cursor: if end < count { Some(end) } else { None },
+        }
     }
 
     fn get_active_campaign_count(env: Env) -> u32 {
-        let all_campaigns: Vec<BytesN<32>> = env
-            .storage()
-            .instance()
-            .get(&StorageKey::AllCampaigns)
-            .unwrap_or(Vec::new(&env));
+        Self::get_campaign_count(env.clone()).saturating_sub(Self::get_expired_campaign_count(env))
+    }
 
+    fn get_expired_campaign_count(env: Env) -> u32 {
+        let expiry: CampaignExpiry = match storage::get(&env, &StorageKey::CampaignExpiry) {
+            Some(expiry) => expiry,
+            None => return 0,
+        };
+
+        // Only a bounded number of buckets past the last settlement are read;
+        // anything beyond them waits for `sweep_expired_campaigns`
         let now = env.ledger().timestamp();
-        let mut count: u32 = 0;
-
-        for id in all_campaigns.iter() {
-            let campaign_key = (id,);
-            if let Some(campaign) = env
-                .storage()
-                .instance()
-                .get::<_, CampaignDetails>(&campaign_key)
-            {
-                if campaign.deadline > now {
-                    count += 1;
+        let last = (now / CAMPAIGN_DEADLINE_BUCKET)
+            .min(expiry.settled_bucket + MAX_UNSETTLED_DEADLINE_BUCKETS - 1);
+        let mut expired = expiry.expired;
+        for bucket in expiry.settled_bucket..=last {
+            let deadlines: Map<u64, u32> =
+                match storage::get(&env, &StorageKey::CampaignDeadlines(bucket)) {
+                    Some(deadlines) => deadlines,
+                    None => continue,
+                };
+            for (deadline, count) in deadlines.iter() {
+                if deadline <= now {
+                    expired += count;
                 }
             }
         }
+        expired
+    }
 
-        count
+    fn sweep_expired_campaigns(env: Env, limit: u32) -> u32 {
+        Self::settle_deadline_buckets(&env, limit)
     }
 
     fn get_donor_count(env: Env, campaign_id: BytesN<32>) -> Result<u32, CrowdfundingError> {
-        let campaign_key = (campaign_id.clone(),);
-        if !env.storage().instance().has(&campaign_key) {
+        let campaign_key = StorageKey::Campaign(campaign_id.clone());
+        if !storage::has(&env, &campaign_key) {
             return Err(CrowdfundingError::CampaignNotFound);
         }
 
         let metrics_key = StorageKey::CampaignMetrics(campaign_id);
-        let metrics: CampaignMetrics = env
-            .storage()
-            .instance()
-            .get(&metrics_key)
-            .unwrap_or_default();
+        let metrics: CampaignMetrics = storage::get(&env, &metrics_key).unwrap_or_default();
         Ok(metrics.contributor_count)
     }
 
     fn get_campaign_balance(env: Env, campaign_id: BytesN<32>) -> Result<i128, CrowdfundingError> {
-        let campaign_key = (campaign_id.clone(),);
-        if !env.storage().instance().has(&campaign_key) {
+        let campaign_key = StorageKey::Campaign(campaign_id.clone());
+        if !storage::has(&env, &campaign_key) {
             return Err(CrowdfundingError::CampaignNotFound);
         }
 
         let metrics_key = StorageKey::CampaignMetrics(campaign_id);
-        let metrics: CampaignMetrics = env
-            .storage()
-            .instance()
-            .get(&metrics_key)
-            .unwrap_or_default();
+        let metrics: CampaignMetrics = storage::get(&env, &metrics_key).unwrap_or_default();
         Ok(metrics.total_raised)
     }
 
@@ -269,14 +504,11 @@ impl CrowdfundingTrait for CrowdfundingContract {
 
         let contribution_key = StorageKey::Contribution(campaign_id.clone(), contributor.clone());
         let contribution: Contribution =
-            env.storage()
-                .instance()
-                .get(&contribution_key)
-                .unwrap_or(Contribution {
-                    campaign_id: campaign_id.clone(),
-                    contributor: contributor.clone(),
-                    amount: 0,
-                });
+            storage::get(&env, &contribution_key).unwrap_or(Contribution {
+                campaign_id: campaign_id.clone(),
+                contributor: contributor.clone(),
+                amount: 0,
+            });
         Ok(contribution.amount)
     }
 
@@ -299,7 +531,7 @@ impl CrowdfundingTrait for CrowdfundingContract {
         let total_raised = Self::get_campaign_balance(env.clone(), campaign_id.clone())?;
         let current_time = env.ledger().timestamp();
         let cancellation_key = StorageKey::CampaignCancelled(campaign_id.clone());
-        let is_cancelled = env.storage().instance().has(&cancellation_key);
+        let is_cancelled = storage::has(&env, &cancellation_key);
 
         let status = CampaignLifecycleStatus::get_status(
             total_raised,
@@ -312,6 +544,64 @@ impl CrowdfundingTrait for CrowdfundingContract {
         Ok(status)
     }
 
+    fn cancel_campaign(
+        env: Env,
+        campaign_id: BytesN<32>,
+        caller: Address,
+        reason: String,
+    ) -> Result<(), CrowdfundingError> {
+        caller.require_auth();
+
+        let campaign = Self::get_campaign(env.clone(), campaign_id.clone())?;
+
+        // Only the campaign creator or the admin may cancel
+        let admin: Option<Address> = storage::get(&env, &StorageKey::Admin);
+        if caller != campaign.creator && Some(caller.clone()) != admin {
+            return Err(CrowdfundingError::Unauthorized);
+        }
+
+        if reason.len() > MAX_DESCRIPTION_LENGTH {
+            return Err(CrowdfundingError::InvalidMetadata);
+        }
+
+        let cancellation_key = StorageKey::CampaignCancelled(campaign_id.clone());
+        if storage::has(&env, &cancellation_key) {
+            return Err(CrowdfundingError::CampaignCancelled);
+        }
+
+        // Once the creator has been paid out, donors can no longer be refunded,
+        // so a funded and withdrawn campaign stays final
+        let withdrawn: i128 =
+            storage::get(&env, &StorageKey::CampaignWithdrawn(campaign_id.clone())).unwrap_or(0);
+        if withdrawn > 0 {
+            return Err(CrowdfundingError::CampaignAlreadyFunded);
+        }
+
+        let now = env.ledger().timestamp();
+        let cancellation = CampaignCancellation {
+            cancelled_by: caller.clone(),
+            reason: reason.clone(),
+            cancelled_at: now,
+        };
+        storage::set(&env, &cancellation_key, &cancellation);
+
+        events::campaign_cancelled(&env, campaign_id, caller, reason, now);
+
+        Ok(())
+    }
+
+    fn get_campaign_cancellation(
+        env: Env,
+        campaign_id: BytesN<32>,
+    ) -> Result<Option<CampaignCancellation>, CrowdfundingError> {
+        Self::get_campaign(env.clone(), campaign_id.clone())?;
+
+        Ok(storage::get(
+            &env,
+            &StorageKey::CampaignCancelled(campaign_id),
+        ))
+    }
+
     fn donate(
         env: Env,
         campaign_id: BytesN<32>,
@@ -332,6 +622,11 @@ impl CrowdfundingTrait for CrowdfundingContract {
         // Get campaign and validate it exists
         let mut campaign = Self::get_campaign(env.clone(), campaign_id.clone())?;
 
+        // Cancelled campaigns no longer accept donations
+        if storage::has(&env, &StorageKey::CampaignCancelled(campaign_id.clone())) {
+            return Err(CrowdfundingError::CampaignCancelled);
+        }
+
         // Check if campaign is still active (deadline hasn't passed)
         if env.ledger().timestamp() >= campaign.deadline {
             return Err(CrowdfundingError::CampaignExpired);
@@ -347,23 +642,28 @@ impl CrowdfundingTrait for CrowdfundingContract {
             return Err(CrowdfundingError::TokenTransferFailed);
         }
 
-        // Transfer tokens from donor to contract
+        // A donation the platform fee would consume entirely is rejected
+        let fee = Self::platform_fee_for(&Self::get_platform_fee(env.clone()), &asset, amount);
+        if amount - fee <= 0 {
+            return Err(CrowdfundingError::InvalidAmount);
+        }
+
+        // Transfer tokens from donor to contract, keeping the platform fee
         use soroban_sdk::token;
         let token_client = token::Client::new(&env, &asset);
         token_client.transfer(&donor, env.current_contract_address(), &amount);
 
+        Self::credit_platform_fees(&env, &asset, fee);
+        let amount = amount - fee;
+
         // Update campaign's total_raised
         campaign.total_raised += amount;
-        let campaign_key = (campaign_id.clone(),);
-        env.storage().instance().set(&campaign_key, &campaign);
+        let campaign_key = StorageKey::Campaign(campaign_id.clone());
+        storage::set(&env, &campaign_key, &campaign);
 
         // Update metrics
         let metrics_key = StorageKey::CampaignMetrics(campaign_id.clone());
-        let mut metrics: CampaignMetrics = env
-            .storage()
-            .instance()
-            .get(&metrics_key)
-            .unwrap_or_default();
+        let mut metrics: CampaignMetrics = storage::get(&env, &metrics_key).unwrap_or_default();
 
         metrics.total_raised += amount;
         metrics.last_donation_at = env.ledger().timestamp();
@@ -376,27 +676,22 @@ impl CrowdfundingTrait for CrowdfundingContract {
 
         // Track unique donor
         let donor_key = StorageKey::CampaignDonor(campaign_id.clone(), donor.clone());
-        if !env.storage().instance().has(&donor_key) {
+        if !storage::has(&env, &donor_key) {
             metrics.contributor_count += 1;
-            env.storage().instance().set(&donor_key, &true);
+            storage::set(&env, &donor_key, &true);
         }
 
-        env.storage().instance().set(&metrics_key, &metrics);
+        storage::set(&env, &metrics_key, &metrics);
 
         // Update global total raised
         let global_key = StorageKey::GlobalTotalRaised;
-        let global_total: i128 = env.storage().instance().get(&global_key).unwrap_or(0i128);
-        env.storage()
-            .instance()
-            .set(&global_key, &(global_total + amount));
+        let global_total: i128 = storage::get(&env, &global_key).unwrap_or(0i128);
+        storage::set(&env, &global_key, &(global_total + amount));
 
         // Store individual contribution
         let contribution_key = StorageKey::Contribution(campaign_id.clone(), donor.clone());
-        let existing_contribution: Contribution = env
-            .storage()
-            .instance()
-            .get(&contribution_key)
-            .unwrap_or(Contribution {
+        let existing_contribution: Contribution =
+            storage::get(&env, &contribution_key).unwrap_or(Contribution {
                 campaign_id: campaign_id.clone(),
                 contributor: donor.clone(),
                 amount: 0,
@@ -407,28 +702,139 @@ impl CrowdfundingTrait for CrowdfundingContract {
             contributor: donor.clone(),
             amount: existing_contribution.amount + amount,
         };
-        env.storage()
-            .instance()
-            .set(&contribution_key, &updated_contribution);
+        storage::set(&env, &contribution_key, &updated_contribution);
 
         // Emit DonationMade event
-        events::donation_made(&env, campaign_id, donor, amount);
+        events::donation_made(&env, campaign_id, donor, amount, fee);
 
         Ok(())
     }
 
+    fn refund_donation(
+        env: Env,
+        campaign_id: BytesN<32>,
+        donor: Address,
+    ) -> Result<i128, CrowdfundingError> {
+        if Self::is_paused(env.clone()) {
+            return Err(CrowdfundingError::ContractPaused);
+        }
+        donor.require_auth();
+
+        let mut campaign = Self::get_campaign(env.clone(), campaign_id.clone())?;
+
+        // Donations are only returned once the campaign can no longer succeed
+        let status = Self::get_campaign_status(env.clone(), campaign_id.clone())?;
+        if status != CampaignLifecycleStatus::Expired
+            && status != CampaignLifecycleStatus::Cancelled
+        {
+            return Err(CrowdfundingError::RefundNotAvailable);
+        }
+
+        let contribution_key = StorageKey::Contribution(campaign_id.clone(), donor.clone());
+        let contribution: Contribution = storage::get(&env, &contribution_key)
+            .ok_or(CrowdfundingError::NoContributionToRefund)?;
+
+        if contribution.amount <= 0 {
+            return Err(CrowdfundingError::NoContributionToRefund);
+        }
+        let amount = contribution.amount;
+
+        use soroban_sdk::token;
+        let token_client = token::Client::new(&env, &campaign.token_address);
+        token_client.transfer(&env.current_contract_address(), &donor, &amount);
+
+        campaign.total_raised -= amount;
+        storage::set(&env, &StorageKey::Campaign(campaign_id.clone()), &campaign);
+
+        // Keep contributor_count as historical data, as pool refunds do
+        let metrics_key = StorageKey::CampaignMetrics(campaign_id.clone());
+        let mut metrics: CampaignMetrics = storage::get(&env, &metrics_key).unwrap_or_default();
+        metrics.total_raised -= amount;
+        storage::set(&env, &metrics_key, &metrics);
+
+        let global_key = StorageKey::GlobalTotalRaised;
+        let global_total: i128 = storage::get(&env, &global_key).unwrap_or(0i128);
+        storage::set(&env, &global_key, &(global_total - amount));
+
+        // Zero out the contribution to prevent double refunds
+        let zeroed_contribution = Contribution {
+            campaign_id: campaign_id.clone(),
+            contributor: donor.clone(),
+            amount: 0,
+        };
+        storage::set(&env, &contribution_key, &zeroed_contribution);
+
+        events::donation_refunded(&env, campaign_id, donor, amount);
+
+        Ok(amount)
+    }
+
+    fn withdraw_campaign_funds(
+        env: Env,
+        campaign_id: BytesN<32>,
+    ) -> Result<i128, CrowdfundingError> {
+        if Self::is_paused(env.clone()) {
+            return Err(CrowdfundingError::ContractPaused);
+        }
+
+        let campaign = Self::get_campaign(env.clone(), campaign_id.clone())?;
+        campaign.creator.require_auth();
+
+        let status = Self::get_campaign_status(env.clone(), campaign_id.clone())?;
+        if status != CampaignLifecycleStatus::Successful {
+            return Err(CrowdfundingError::CampaignNotSuccessful);
+        }
+
+        // Only the portion not yet claimed by the creator can be withdrawn
+        let withdrawn_key = StorageKey::CampaignWithdrawn(campaign_id.clone());
+        let already_withdrawn: i128 = storage::get(&env, &withdrawn_key).unwrap_or(0);
+        let available = campaign.total_raised - already_withdrawn;
+        if available <= 0 {
+            return Err(CrowdfundingError::NothingToWithdraw);
+        }
+
+        use soroban_sdk::token;
+        let token_client = token::Client::new(&env, &campaign.token_address);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &campaign.creator,
+            &available,
+        );
+
+        let total_withdrawn = already_withdrawn + available;
+        storage::set(&env, &withdrawn_key, &total_withdrawn);
+
+        events::campaign_funds_withdrawn(
+            &env,
+            campaign_id,
+            campaign.creator,
+            available,
+            total_withdrawn,
+        );
+
+        Ok(available)
+    }
+
+    fn get_campaign_withdrawn(
+        env: Env,
+        campaign_id: BytesN<32>,
+    ) -> Result<i128, CrowdfundingError> {
+        Self::get_campaign(env.clone(), campaign_id.clone())?;
+
+        Ok(storage::get(&env, &StorageKey::CampaignWithdrawn(campaign_id)).unwrap_or(0))
+    }
+
     fn get_campaign(env: Env, id: BytesN<32>) -> Result<CampaignDetails, CrowdfundingError> {
-        let campaign_key = (id,);
-        env.storage()
-            .instance()
-            .get(&campaign_key)
-            .ok_or(CrowdfundingError::CampaignNotFound)
+        let campaign_key = StorageKey::Campaign(id);
+        storage::get(&env, &campaign_key).ok_or(CrowdfundingError::CampaignNotFound)
     }
 
     fn create_pool(
         env: Env,
         creator: Address,
         config: PoolConfig,
+        accepted_assets: Option<Vec<Address>>,
+        verified_recipients_only: bool,
     ) -> Result<u64, CrowdfundingError> {
         if Self::is_paused(env.clone()) {
             return Err(CrowdfundingError::ContractPaused);
@@ -437,36 +843,43 @@ impl CrowdfundingTrait for CrowdfundingContract {
 
         // Validate config
         config.validate();
+        let accepted_assets = Self::resolve_accepted_assets(&env, accepted_assets)?;
 
         // Extra validation (if any, e.g. duration checks not covered by validate)
         // For now relying on PoolConfig::validate
 
         // Generate unique pool ID
         let next_id_key = StorageKey::NextPoolId;
-        let pool_id = env.storage().instance().get(&next_id_key).unwrap_or(1u64);
+        let pool_id = storage::get(&env, &next_id_key).unwrap_or(1u64);
         let new_next_id = pool_id + 1;
 
-        // Check uniqueness (redundant with sequential IDs but safe)
         let pool_key = StorageKey::Pool(pool_id);
-        if env.storage().instance().has(&pool_key) {
-            return Err(CrowdfundingError::PoolAlreadyExists);
-        }
 
         // Store config
-        env.storage().instance().set(&pool_key, &config);
+        storage::set(&env, &pool_key, &config);
+        storage::set(&env, &StorageKey::PoolCreator(pool_id), &creator);
+        Self::index_creator_pool(&env, &creator, pool_id);
+        if let Some(assets) = accepted_assets {
+            storage::set(&env, &StorageKey::PoolAcceptedAssets(pool_id), &assets);
+        }
+        if verified_recipients_only {
+            storage::set(
+                &env,
+                &StorageKey::PoolVerifiedRecipientsOnly(pool_id),
+                &true,
+            );
+        }
 
         // Initialize state
         let state_key = StorageKey::PoolState(pool_id);
-        env.storage().instance().set(&state_key, &PoolState::Active);
+        storage::set(&env, &state_key, &PoolState::Active);
 
         // Initialize metrics
         let metrics_key = StorageKey::PoolMetrics(pool_id);
-        env.storage()
-            .instance()
-            .set(&metrics_key, &PoolMetrics::new());
+        storage::set(&env, &metrics_key, &PoolMetrics::new());
 
         // Update ID counter
-        env.storage().instance().set(&next_id_key, &new_next_id);
+        storage::set(&env, &next_id_key, &new_next_id);
 
         // Emit event
         // Calculate deadline from creation time and duration for the event
@@ -494,6 +907,8 @@ impl CrowdfundingTrait for CrowdfundingContract {
         deadline: u64,
         required_signatures: Option<u32>,
         signers: Option<Vec<Address>>,
+        accepted_assets: Option<Vec<Address>>,
+        verified_recipients_only: bool,
     ) -> Result<u64, CrowdfundingError> {
         if Self::is_paused(env.clone()) {
             return Err(CrowdfundingError::ContractPaused);
@@ -540,16 +955,14 @@ impl CrowdfundingTrait for CrowdfundingContract {
             _ => return Err(CrowdfundingError::InvalidMultiSigConfig),
         };
 
+        let accepted_assets = Self::resolve_accepted_assets(&env, accepted_assets)?;
+
         // Generate unique pool ID
         let next_id_key = StorageKey::NextPoolId;
-        let pool_id = env.storage().instance().get(&next_id_key).unwrap_or(1u64);
+        let pool_id = storage::get(&env, &next_id_key).unwrap_or(1u64);
         let new_next_id = pool_id + 1;
 
-        // Check if pool already exists (shouldn't happen with auto-increment)
         let pool_key = StorageKey::Pool(pool_id);
-        if env.storage().instance().has(&pool_key) {
-            return Err(CrowdfundingError::PoolAlreadyExists);
-        }
 
         // Derive pool duration from requested deadline and current timestamp
         let now = env.ledger().timestamp();
@@ -566,29 +979,42 @@ impl CrowdfundingTrait for CrowdfundingContract {
         };
 
         // Store pool configuration
-        env.storage().instance().set(&pool_key, &pool_config);
+        storage::set(&env, &pool_key, &pool_config);
+        storage::set(&env, &StorageKey::PoolCreator(pool_id), &creator);
+        Self::index_creator_pool(&env, &creator, pool_id);
 
         // Store pool metadata in persistent storage
         let metadata_key = StorageKey::PoolMetadata(pool_id);
-        env.storage().persistent().set(&metadata_key, &metadata);
+        storage::set(&env, &metadata_key, &metadata);
 
         // Store multi-sig config separately if provided
         if let Some(config) = multi_sig_config {
             let multi_sig_key = StorageKey::MultiSigConfig(pool_id);
-            env.storage().instance().set(&multi_sig_key, &config);
+            storage::set(&env, &multi_sig_key, &config);
+        }
+
+        if let Some(assets) = accepted_assets {
+            storage::set(&env, &StorageKey::PoolAcceptedAssets(pool_id), &assets);
+        }
+        if verified_recipients_only {
+            storage::set(
+                &env,
+                &StorageKey::PoolVerifiedRecipientsOnly(pool_id),
+                &true,
+            );
         }
 
         // Initialize pool state as Active
         let state_key = StorageKey::PoolState(pool_id);
-        env.storage().instance().set(&state_key, &PoolState::Active);
+        storage::set(&env, &state_key, &PoolState::Active);
 
         // Initialize empty metrics
         let metrics_key = StorageKey::PoolMetrics(pool_id);
         let initial_metrics = PoolMetrics::new();
-        env.storage().instance().set(&metrics_key, &initial_metrics);
+        storage::set(&env, &metrics_key, &initial_metrics);
 
         // Update next pool ID
-        env.storage().instance().set(&next_id_key, &new_next_id);
+        storage::set(&env, &next_id_key, &new_next_id);
 
         // Emit event (assuming events module has pool_created function)
         events::pool_created(
@@ -606,16 +1032,62 @@ impl CrowdfundingTrait for CrowdfundingContract {
 
     fn get_pool(env: Env, pool_id: u64) -> Option<PoolConfig> {
         let pool_key = StorageKey::Pool(pool_id);
-        env.storage().instance().get(&pool_key)
+        storage::get(&env, &pool_key)
+    }
+
+    fn get_creator_pool_count(env: Env, creator: Address) -> u32 {
+        Self::creator_index_counts(&env, creator).pools
+    }
+
+    fn get_creator_pools(env: Env, creator: Address, start: u32, limit: u32) -> Vec<u64> {
+        let count = Self::get_creator_pool_count(env.clone(), creator.clone());
+        let end = start.saturating_add(Self::page_size(limit)).min(count);
+
+        let mut ids = Vec::new(&env);
+        for index in start..end {
+            if let Some(pool_id) =
+                storage::get(&env, &StorageKey::CreatorPool(creator.clone(), index))
+            {
+                ids.push_back(pool_id);
+            }
+        }
+        ids
+    }
+
+    fn list_pools(env: Env, cursor: u64, limit: u32, state: Option<PoolState>) -> PoolPage {
+        // Pool IDs are sequential, starting at 1
+        let next_id: u64 = storage::get(&env, &StorageKey::NextPoolId).unwrap_or(1u64);
+        let start = cursor.max(1);
+        let end = start
+            .saturating_add(Self::page_size(limit) as u64)
+            .min(next_id);
+
+        let mut items = Vec::new(&env);
+        for pool_id in start..end {
+            if let Some(summary) = Self::pool_summary(&env, pool_id) {
+                if state.is_none() || state == Some(summary.state.clone()) {
+                    items.push_back(summary);
+                }
+            }
+        }
+
+        PoolPage {
+            items,
+            next_cursor: if end < next_id { Some(end) } else { None },
+        }
+    }
+
+    fn is_pool_verified_recipients_only(env: Env, pool_id: u64) -> Result<bool, CrowdfundingError> {
+        if !storage::has(&env, &StorageKey::Pool(pool_id)) {
+            return Err(CrowdfundingError::PoolNotFound);
+        }
+
+        Ok(storage::get(&env, &StorageKey::PoolVerifiedRecipientsOnly(pool_id)).unwrap_or(false))
     }
 
     fn get_pool_metadata(env: Env, pool_id: u64) -> (String, String, String) {
         let metadata_key = StorageKey::PoolMetadata(pool_id);
-        if let Some(metadata) = env
-            .storage()
-            .persistent()
-            .get::<StorageKey, PoolMetadata>(&metadata_key)
-        {
+        if let Some(metadata) = storage::get::<PoolMetadata>(&env, &metadata_key) {
             (
                 metadata.description,
                 metadata.external_url,
@@ -630,40 +1102,378 @@ impl CrowdfundingTrait for CrowdfundingContract {
         }
     }
 
+    fn get_pool_creator(env: Env, pool_id: u64) -> Result<Address, CrowdfundingError> {
+        storage::get(&env, &StorageKey::PoolCreator(pool_id)).ok_or(CrowdfundingError::PoolNotFound)
+    }
+
     fn update_pool_state(
         env: Env,
         pool_id: u64,
         new_state: PoolState,
+        caller: Address,
     ) -> Result<(), CrowdfundingError> {
         if Self::is_paused(env.clone()) {
             return Err(CrowdfundingError::ContractPaused);
         }
+        caller.require_auth();
+
         let pool_key = StorageKey::Pool(pool_id);
-        if !env.storage().instance().has(&pool_key) {
+        if !storage::has(&env, &pool_key) {
             return Err(CrowdfundingError::PoolNotFound);
         }
 
-        // Validate state transition (optional - could add more complex logic)
-        let state_key = StorageKey::PoolState(pool_id);
-        let current_state: PoolState = env
-            .storage()
-            .instance()
-            .get(&state_key)
-            .unwrap_or(PoolState::Active);
-
-        // Prevent invalid state transitions
-        match (&current_state, &new_state) {
-            (PoolState::Completed, _) | (PoolState::Cancelled, _) => {
-                return Err(CrowdfundingError::InvalidPoolState);
-            }
-            _ => {} // Allow other transitions
+        Self::ensure_pool_manager(&env, pool_id, &caller)?;
+
+        let current_state = state_machine::get_state(&env, pool_id);
+        state_machine::validate_manual_transition(&current_state, &new_state)?;
+        state_machine::transition(&env, pool_id, new_state, caller)
+    }
+
+    fn set_pool_cap_mode(
+        env: Env,
+        pool_id: u64,
+        caller: Address,
+        mode: PoolCapMode,
+    ) -> Result<(), CrowdfundingError> {
+        if Self::is_paused(env.clone()) {
+            return Err(CrowdfundingError::ContractPaused);
+        }
+        caller.require_auth();
+
+        if !storage::has(&env, &StorageKey::Pool(pool_id)) {
+            return Err(CrowdfundingError::PoolNotFound);
         }
 
-        // Update state
-        env.storage().instance().set(&state_key, &new_state);
+        Self::ensure_pool_manager(&env, pool_id, &caller)?;
 
-        // Emit event
-        events::pool_state_updated(&env, pool_id, new_state);
+        // The cap only matters while the pool is still collecting funds
+        if state_machine::get_state(&env, pool_id) != PoolState::Active {
+            return Err(CrowdfundingError::InvalidPoolState);
+        }
+
+        storage::set(&env, &StorageKey::PoolCapMode(pool_id), &mode);
+
+        events::pool_cap_mode_updated(&env, pool_id, caller, mode);
+
+        Ok(())
+    }
+
+    fn get_pool_cap_mode(env: Env, pool_id: u64) -> Result<PoolCapMode, CrowdfundingError> {
+        if !storage::has(&env, &StorageKey::Pool(pool_id)) {
+            return Err(CrowdfundingError::PoolNotFound);
+        }
+
+        Ok(storage::get(&env, &StorageKey::PoolCapMode(pool_id)).unwrap_or(PoolCapMode::Soft))
+    }
+
+    fn get_pool_state(env: Env, pool_id: u64) -> Result<PoolState, CrowdfundingError> {
+        if !storage::has(&env, &StorageKey::Pool(pool_id)) {
+            return Err(CrowdfundingError::PoolNotFound);
+        }
+
+        Ok(state_machine::get_state(&env, pool_id))
+    }
+
+    fn allowed_transitions(env: Env, pool_id: u64) -> Result<Vec<PoolState>, CrowdfundingError> {
+        let current_state = Self::get_pool_state(env.clone(), pool_id)?;
+        Ok(state_machine::next_manual_states(&env, &current_state))
+    }
+
+    fn get_pool_state_history(
+        env: Env,
+        pool_id: u64,
+    ) -> Result<Vec<PoolStateChange>, CrowdfundingError> {
+        if !storage::has(&env, &StorageKey::Pool(pool_id)) {
+            return Err(CrowdfundingError::PoolNotFound);
+        }
+
+        Ok(state_machine::get_history(&env, pool_id))
+    }
+
+    fn get_multisig_config(env: Env, pool_id: u64) -> Result<MultiSigConfig, CrowdfundingError> {
+        let pool_key = StorageKey::Pool(pool_id);
+        if !storage::has(&env, &pool_key) {
+            return Err(CrowdfundingError::PoolNotFound);
+        }
+
+        storage::get(&env, &StorageKey::MultiSigConfig(pool_id))
+            .ok_or(CrowdfundingError::InvalidMultiSigConfig)
+    }
+
+    fn propose_disbursement(
+        env: Env,
+        pool_id: u64,
+        proposer: Address,
+        recipient: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<u64, CrowdfundingError> {
+        if Self::is_paused(env.clone()) {
+            return Err(CrowdfundingError::ContractPaused);
+        }
+        proposer.require_auth();
+
+        if amount <= 0 {
+            return Err(CrowdfundingError::InvalidAmount);
+        }
+
+        let config = Self::get_multisig_config(env.clone(), pool_id)?;
+        if !config.signers.contains(&proposer) {
+            return Err(CrowdfundingError::NotAuthorizedSigner);
+        }
+
+        Self::ensure_pool_disbursable(&env, pool_id, &asset, amount)?;
+        Self::ensure_pool_recipient_allowed(&env, pool_id, &recipient)?;
+
+        // Disbursement IDs are sequential per pool, starting at 1
+        let next_id_key = StorageKey::NextDisbursementId(pool_id);
+        let disbursement_id: u64 = storage::get(&env, &next_id_key).unwrap_or(1u64);
+
+        // The proposer implicitly approves their own proposal
+        let mut approvals = Vec::new(&env);
+        approvals.push_back(proposer.clone());
+
+        let request = DisbursementRequest {
+            pool_id,
+            amount,
+            asset: asset.clone(),
+            recipient: recipient.clone(),
+            approvals,
+            created_at: env.ledger().timestamp(),
+            executed: false,
+        };
+
+        storage::set(
+            &env,
+            &StorageKey::DisbursementRequest(pool_id, disbursement_id),
+            &request,
+        );
+        storage::set(&env, &next_id_key, &(disbursement_id + 1));
+
+        events::disbursement_proposed(
+            &env,
+            pool_id,
+            disbursement_id,
+            proposer.clone(),
+            recipient,
+            asset,
+            amount,
+        );
+        events::disbursement_approved(&env, pool_id, disbursement_id, proposer, 1);
+
+        Ok(disbursement_id)
+    }
+
+    fn approve_disbursement(
+        env: Env,
+        pool_id: u64,
+        disbursement_id: u64,
+        signer: Address,
+    ) -> Result<(), CrowdfundingError> {
+        if Self::is_paused(env.clone()) {
+            return Err(CrowdfundingError::ContractPaused);
+        }
+        signer.require_auth();
+
+        let config = Self::get_multisig_config(env.clone(), pool_id)?;
+        if !config.signers.contains(&signer) {
+            return Err(CrowdfundingError::NotAuthorizedSigner);
+        }
+
+        let request_key = StorageKey::DisbursementRequest(pool_id, disbursement_id);
+        let mut request: DisbursementRequest =
+            storage::get(&env, &request_key).ok_or(CrowdfundingError::DisbursementNotFound)?;
+
+        if request.executed {
+            return Err(CrowdfundingError::DisbursementAlreadyExecuted);
+        }
+
+        if request.approvals.contains(&signer) {
+            return Err(CrowdfundingError::AlreadyApproved);
+        }
+
+        request.approvals.push_back(signer.clone());
+        storage::set(&env, &request_key, &request);
+
+        events::disbursement_approved(
+            &env,
+            pool_id,
+            disbursement_id,
+            signer,
+            request.approvals.len(),
+        );
+
+        Ok(())
+    }
+
+    fn execute_disbursement(
+        env: Env,
+        pool_id: u64,
+        disbursement_id: u64,
+        executor: Address,
+    ) -> Result<(), CrowdfundingError> {
+        if Self::is_paused(env.clone()) {
+            return Err(CrowdfundingError::ContractPaused);
+        }
+        executor.require_auth();
+
+        let config = Self::get_multisig_config(env.clone(), pool_id)?;
+        if !config.signers.contains(&executor) {
+            return Err(CrowdfundingError::NotAuthorizedSigner);
+        }
+
+        let request_key = StorageKey::DisbursementRequest(pool_id, disbursement_id);
+        let mut request: DisbursementRequest =
+            storage::get(&env, &request_key).ok_or(CrowdfundingError::DisbursementNotFound)?;
+
+        if request.executed {
+            return Err(CrowdfundingError::DisbursementAlreadyExecuted);
+        }
+
+        // Only approvals from signers that are still in the set count
+        // towards the threshold
+        let valid_approvals = request
+            .approvals
+            .iter()
+            .filter(|approver| config.signers.contains(approver))
+            .count() as u32;
+        if valid_approvals < config.required_signatures {
+            return Err(CrowdfundingError::InsufficientApprovals);
+        }
+
+        Self::ensure_pool_disbursable(&env, pool_id, &request.asset, request.amount)?;
+        Self::ensure_pool_recipient_allowed(&env, pool_id, &request.recipient)?;
+
+        use soroban_sdk::token;
+        let token_client = token::Client::new(&env, &request.asset);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &request.recipient,
+            &request.amount,
+        );
+
+        request.executed = true;
+        storage::set(&env, &request_key, &request);
+        storage::set(&env, &StorageKey::PoolPayoutExecuted(pool_id), &true);
+        Self::adjust_pool_asset_total(&env, pool_id, &request.asset, -request.amount);
+
+        events::disbursement_executed(
+            &env,
+            pool_id,
+            disbursement_id,
+            request.recipient,
+            request.asset,
+            request.amount,
+        );
+
+        // Partial payouts leave the pool open so the remainder can still be
+        // disbursed; it is only marked disbursed once nothing is left
+        if Self::pool_holds_funds(&env, pool_id) {
+            return Ok(());
+        }
+        state_machine::transition(&env, pool_id, PoolState::Disbursed, executor)
+    }
+
+    fn get_disbursement(
+        env: Env,
+        pool_id: u64,
+        disbursement_id: u64,
+    ) -> Result<DisbursementRequest, CrowdfundingError> {
+        storage::get(
+            &env,
+            &StorageKey::DisbursementRequest(pool_id, disbursement_id),
+        )
+        .ok_or(CrowdfundingError::DisbursementNotFound)
+    }
+
+    fn get_disbursement_count(env: Env, pool_id: u64) -> u64 {
+        let next_id: u64 =
+            storage::get(&env, &StorageKey::NextDisbursementId(pool_id)).unwrap_or(1u64);
+        next_id - 1
+    }
+
+    fn add_signer(
+        env: Env,
+        pool_id: u64,
+        approvers: Vec<Address>,
+        new_signer: Address,
+    ) -> Result<(), CrowdfundingError> {
+        if Self::is_paused(env.clone()) {
+            return Err(CrowdfundingError::ContractPaused);
+        }
+
+        let mut config = Self::get_multisig_config(env.clone(), pool_id)?;
+        Self::require_signer_quorum(&config, &approvers)?;
+
+        if config.signers.contains(&new_signer) {
+            return Err(CrowdfundingError::SignerAlreadyExists);
+        }
+
+        config.signers.push_back(new_signer.clone());
+        storage::set(&env, &StorageKey::MultiSigConfig(pool_id), &config);
+
+        events::signer_added(&env, pool_id, new_signer, config.signers.len());
+
+        Ok(())
+    }
+
+    fn remove_signer(
+        env: Env,
+        pool_id: u64,
+        approvers: Vec<Address>,
+        signer: Address,
+    ) -> Result<(), CrowdfundingError> {
+        if Self::is_paused(env.clone()) {
+            return Err(CrowdfundingError::ContractPaused);
+        }
+
+        let mut config = Self::get_multisig_config(env.clone(), pool_id)?;
+        Self::require_signer_quorum(&config, &approvers)?;
+
+        let index = config
+            .signers
+            .first_index_of(&signer)
+            .ok_or(CrowdfundingError::SignerNotFound)?;
+
+        if config.signers.len() == 1 {
+            return Err(CrowdfundingError::CannotRemoveLastSigner);
+        }
+
+        // The remaining signers must still be able to reach the threshold
+        if config.signers.len() - 1 < config.required_signatures {
+            return Err(CrowdfundingError::InvalidSignerCount);
+        }
+
+        config.signers.remove(index);
+        storage::set(&env, &StorageKey::MultiSigConfig(pool_id), &config);
+
+        events::signer_removed(&env, pool_id, signer, config.signers.len());
+
+        Ok(())
+    }
+
+    fn set_required_signatures(
+        env: Env,
+        pool_id: u64,
+        approvers: Vec<Address>,
+        required_signatures: u32,
+    ) -> Result<(), CrowdfundingError> {
+        if Self::is_paused(env.clone()) {
+            return Err(CrowdfundingError::ContractPaused);
+        }
+
+        let mut config = Self::get_multisig_config(env.clone(), pool_id)?;
+        Self::require_signer_quorum(&config, &approvers)?;
+
+        if required_signatures == 0 || required_signatures > config.signers.len() {
+            return Err(CrowdfundingError::InvalidMultiSigConfig);
+        }
+
+        let old_required = config.required_signatures;
+        config.required_signatures = required_signatures;
+        storage::set(&env, &StorageKey::MultiSigConfig(pool_id), &config);
+
+        events::required_signatures_updated(&env, pool_id, old_required, required_signatures);
 
         Ok(())
     }
@@ -674,7 +1484,7 @@ impl CrowdfundingTrait for CrowdfundingContract {
         token: Address,
         creation_fee: i128,
     ) -> Result<(), CrowdfundingError> {
-        if env.storage().instance().has(&StorageKey::Admin) {
+        if storage::has(&env, &StorageKey::Admin) {
             return Err(CrowdfundingError::ContractAlreadyInitialized);
         }
 
@@ -682,56 +1492,44 @@ impl CrowdfundingTrait for CrowdfundingContract {
             return Err(CrowdfundingError::InvalidFee);
         }
 
-        env.storage().instance().set(&StorageKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&StorageKey::CrowdfundingToken, &token);
-        env.storage()
-            .instance()
-            .set(&StorageKey::CreationFee, &creation_fee);
-        env.storage().instance().set(&StorageKey::IsPaused, &false);
+        storage::set(&env, &StorageKey::Admin, &admin);
+        storage::set(&env, &StorageKey::CrowdfundingToken, &token);
+        storage::set(&env, &StorageKey::CreationFee, &creation_fee);
+        storage::set(&env, &StorageKey::IsPaused, &false);
+        Self::register_token(&env, &token);
         Ok(())
     }
 
     fn pause(env: Env) -> Result<(), CrowdfundingError> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::NotInitialized)?;
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
         admin.require_auth();
 
         if Self::is_paused(env.clone()) {
             return Err(CrowdfundingError::ContractAlreadyPaused);
         }
 
-        env.storage().instance().set(&StorageKey::IsPaused, &true);
+        storage::set(&env, &StorageKey::IsPaused, &true);
         events::contract_paused(&env, admin, env.ledger().timestamp());
         Ok(())
     }
 
     fn unpause(env: Env) -> Result<(), CrowdfundingError> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::NotInitialized)?;
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
         admin.require_auth();
 
         if !Self::is_paused(env.clone()) {
             return Err(CrowdfundingError::ContractAlreadyUnpaused);
         }
 
-        env.storage().instance().set(&StorageKey::IsPaused, &false);
+        storage::set(&env, &StorageKey::IsPaused, &false);
         events::contract_unpaused(&env, admin, env.ledger().timestamp());
         Ok(())
     }
 
     fn is_paused(env: Env) -> bool {
-        env.storage()
-            .instance()
-            .get(&StorageKey::IsPaused)
-            .unwrap_or(false)
+        storage::get(&env, &StorageKey::IsPaused).unwrap_or(false)
     }
 
     fn contribute(
@@ -752,19 +1550,54 @@ impl CrowdfundingTrait for CrowdfundingContract {
         }
 
         let pool_key = StorageKey::Pool(pool_id);
-        if !env.storage().instance().has(&pool_key) {
-            return Err(CrowdfundingError::PoolNotFound);
+        let pool: PoolConfig =
+            storage::get(&env, &pool_key).ok_or(CrowdfundingError::PoolNotFound)?;
+
+        if state_machine::get_state(&env, pool_id) != PoolState::Active {
+            return Err(CrowdfundingError::InvalidPoolState);
         }
 
-        let state_key = StorageKey::PoolState(pool_id);
-        let state: PoolState = env
-            .storage()
-            .instance()
-            .get(&state_key)
-            .unwrap_or(PoolState::Active);
+        let accepted_assets = Self::get_pool_accepted_assets(env.clone(), pool_id)?;
+        if !accepted_assets.contains(&asset)
+            || !Self::is_token_supported(env.clone(), asset.clone())
+        {
+            return Err(CrowdfundingError::InvalidToken);
+        }
+        // The target and hard cap are measured in the first accepted asset;
+        // other assets are collected without counting towards either
+        let counts_towards_target = accepted_assets.first_index_of(&asset) == Some(0);
 
-        if state != PoolState::Active {
-            return Err(CrowdfundingError::InvalidPoolState);
+        // Contributions close at the pool deadline, as donations do for campaigns
+        if env.ledger().timestamp() >= pool.created_at + pool.duration {
+            return Err(CrowdfundingError::CampaignExpired);
+        }
+
+        let metrics_key = StorageKey::PoolMetrics(pool_id);
+        let mut metrics: PoolMetrics = storage::get(&env, &metrics_key).unwrap_or_default();
+
+        // Apply the pool's hard-cap mode to amounts past the target. The cap
+        // counts what the pool keeps once the platform fee is taken
+        let remaining = pool.target_amount - metrics.total_raised;
+        let fee_config = Self::get_platform_fee(env.clone());
+        let cap_mode: PoolCapMode = if counts_towards_target {
+            storage::get(&env, &StorageKey::PoolCapMode(pool_id)).unwrap_or(PoolCapMode::Soft)
+        } else {
+            PoolCapMode::Soft
+        };
+        let amount = match cap_mode {
+            PoolCapMode::Soft => amount,
+            PoolCapMode::Reject
+                if amount - Self::platform_fee_for(&fee_config, &asset, amount) > remaining =>
+            {
+                return Err(CrowdfundingError::InvalidAmount);
+            }
+            PoolCapMode::Reject => amount,
+            PoolCapMode::Trim => Self::trim_to_net(&fee_config, &asset, amount, remaining),
+        };
+        // Nothing may be recorded for a contribution the fee consumes entirely
+        let fee = Self::platform_fee_for(&fee_config, &asset, amount);
+        if amount - fee <= 0 {
+            return Err(CrowdfundingError::InvalidAmount);
         }
 
         // Transfer tokens
@@ -774,47 +1607,46 @@ impl CrowdfundingTrait for CrowdfundingContract {
         let token_client = token::Client::new(&env, &asset);
         token_client.transfer(&contributor, env.current_contract_address(), &amount);
 
-        // Update metrics
-        let metrics_key = StorageKey::PoolMetrics(pool_id);
-        let mut metrics: PoolMetrics = env
-            .storage()
-            .instance()
-            .get(&metrics_key)
-            .unwrap_or_default();
-
-        // Track unique contributor
-        let contributor_key = StorageKey::PoolContribution(pool_id, contributor.clone());
-        let existing_contribution: PoolContribution = env
-            .storage()
-            .instance()
-            .get(&contributor_key)
-            .unwrap_or(PoolContribution {
-                pool_id,
-                contributor: contributor.clone(),
-                amount: 0,
-                asset: asset.clone(),
-            });
+        Self::credit_platform_fees(&env, &asset, fee);
+        let amount = amount - fee;
+
+        Self::migrate_legacy_pool_contribution(&env, pool_id, &contributor);
+
+        // Track unique contributor and the assets they have given
+        let assets_key = StorageKey::PoolContributorAssets(pool_id, contributor.clone());
+        let mut contributor_assets: Vec<Address> =
+            storage::get(&env, &assets_key).unwrap_or(Vec::new(&env));
 
         // Only increment contributor_count if this is a new contributor
-        if existing_contribution.amount == 0 {
+        if contributor_assets.is_empty() {
             metrics.contributor_count += 1;
         }
+        if !contributor_assets.contains(&asset) {
+            contributor_assets.push_back(asset.clone());
+            storage::set(&env, &assets_key, &contributor_assets);
+        }
 
-        metrics.total_raised += amount;
+        if counts_towards_target {
+            metrics.total_raised += amount;
+        }
         metrics.last_donation_at = env.ledger().timestamp();
 
-        env.storage().instance().set(&metrics_key, &metrics);
+        storage::set(&env, &metrics_key, &metrics);
+        Self::adjust_pool_asset_total(&env, pool_id, &asset, amount);
 
-        // Update per-user contribution tracking
+        // Update per-user, per-asset contribution tracking
+        let contributor_key =
+            StorageKey::PoolContribution(pool_id, contributor.clone(), asset.clone());
+        let existing_amount = storage::get::<PoolContribution>(&env, &contributor_key)
+            .map(|contribution| contribution.amount)
+            .unwrap_or(0);
         let updated_contribution = PoolContribution {
             pool_id,
             contributor: contributor.clone(),
-            amount: existing_contribution.amount + amount,
+            amount: existing_amount + amount,
             asset: asset.clone(),
         };
-        env.storage()
-            .instance()
-            .set(&contributor_key, &updated_contribution);
+        storage::set(&env, &contributor_key, &updated_contribution);
 
         // Emit event
         events::contribution(
@@ -823,10 +1655,22 @@ impl CrowdfundingTrait for CrowdfundingContract {
             contributor,
             asset,
             amount,
+            fee,
             env.ledger().timestamp(),
             is_private,
         );
 
+        // Reaching the target completes the pool automatically
+        if counts_towards_target && metrics.total_raised >= pool.target_amount {
+            events::pool_target_reached(&env, pool_id, pool.target_amount, metrics.total_raised);
+            state_machine::transition(
+                &env,
+                pool_id,
+                PoolState::Completed,
+                env.current_contract_address(),
+            )?;
+        }
+
         Ok(())
     }
 
@@ -838,11 +1682,8 @@ impl CrowdfundingTrait for CrowdfundingContract {
 
         // Validate pool exists
         let pool_key = StorageKey::Pool(pool_id);
-        let pool: PoolConfig = env
-            .storage()
-            .instance()
-            .get(&pool_key)
-            .ok_or(CrowdfundingError::PoolNotFound)?;
+        let pool: PoolConfig =
+            storage::get(&env, &pool_key).ok_or(CrowdfundingError::PoolNotFound)?;
 
         // Check if pool has a deadline (duration > 0)
         if pool.duration == 0 {
@@ -858,15 +1699,12 @@ impl CrowdfundingTrait for CrowdfundingContract {
             return Err(CrowdfundingError::PoolNotExpired);
         }
 
-        // Check if pool is already disbursed
-        let state_key = StorageKey::PoolState(pool_id);
-        let state: PoolState = env
-            .storage()
-            .instance()
-            .get(&state_key)
-            .unwrap_or(PoolState::Active);
+        // Check if pool funds are still held by the contract
+        state_machine::validate_refund(&state_machine::get_state(&env, pool_id))?;
 
-        if state == PoolState::Disbursed {
+        // Once part of the pool has been paid out the remainder can no longer
+        // cover every contribution, so it is left for further disbursements
+        if storage::has(&env, &StorageKey::PoolPayoutExecuted(pool_id)) {
             return Err(CrowdfundingError::PoolAlreadyDisbursed);
         }
 
@@ -879,83 +1717,132 @@ impl CrowdfundingTrait for CrowdfundingContract {
             return Err(CrowdfundingError::RefundGracePeriodNotPassed);
         }
 
-        // Get contributor's contribution
-        let contribution_key = StorageKey::PoolContribution(pool_id, contributor.clone());
-        let contribution: PoolContribution = env
-            .storage()
-            .instance()
-            .get(&contribution_key)
-            .ok_or(CrowdfundingError::NoContributionToRefund)?;
+        Self::migrate_legacy_pool_contribution(&env, pool_id, &contributor);
 
-        if contribution.amount <= 0 {
-            return Err(CrowdfundingError::NoContributionToRefund);
-        }
+        // Refund every asset the contributor gave, each in its own token
+        let contributor_assets: Vec<Address> = storage::get(
+            &env,
+            &StorageKey::PoolContributorAssets(pool_id, contributor.clone()),
+        )
+        .unwrap_or(Vec::new(&env));
+
+        let metrics_key = StorageKey::PoolMetrics(pool_id);
+        let mut metrics: PoolMetrics = storage::get(&env, &metrics_key).unwrap_or_default();
+        let target_asset = Self::pool_target_asset(&env, pool_id);
 
-        // Transfer tokens back to contributor
         use soroban_sdk::token;
-        let token_client = token::Client::new(&env, &contribution.asset);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &contributor,
-            &contribution.amount,
-        );
+        let mut refunded_any = false;
+        for asset in contributor_assets.iter() {
+            let contribution_key =
+                StorageKey::PoolContribution(pool_id, contributor.clone(), asset.clone());
+            let contribution: PoolContribution = match storage::get(&env, &contribution_key) {
+                Some(contribution) => contribution,
+                None => continue,
+            };
+            if contribution.amount <= 0 {
+                continue;
+            }
 
-        // Update pool metrics
-        let metrics_key = StorageKey::PoolMetrics(pool_id);
-        let mut metrics: PoolMetrics = env
-            .storage()
-            .instance()
-            .get(&metrics_key)
-            .unwrap_or(PoolMetrics::new());
+            let amount = contribution.amount;
+            let token_client = token::Client::new(&env, &asset);
+            token_client.transfer(&env.current_contract_address(), &contributor, &amount);
 
-        metrics.total_raised -= contribution.amount;
-        // Note: We don't decrement contributor_count as the contributor may have other contributions
-        // or we want to keep historical data
+            // Note: We don't decrement contributor_count as we want to keep historical data
+            if target_asset.as_ref() == Some(&asset) {
+                metrics.total_raised -= amount;
+            }
+            Self::adjust_pool_asset_total(&env, pool_id, &asset, -amount);
 
-        env.storage().instance().set(&metrics_key, &metrics);
+            // We zero it out to prevent double refunds while keeping historical record
+            let zeroed_contribution = PoolContribution {
+                pool_id,
+                contributor: contributor.clone(),
+                amount: 0,
+                asset: asset.clone(),
+            };
+            storage::set(&env, &contribution_key, &zeroed_contribution);
 
-        // Remove or zero out the contribution record
-        // We zero it out to prevent double refunds while keeping historical record
-        let zeroed_contribution = PoolContribution {
-            pool_id,
-            contributor: contributor.clone(),
-            amount: 0,
-            asset: contribution.asset.clone(),
-        };
-        env.storage()
-            .instance()
-            .set(&contribution_key, &zeroed_contribution);
+            events::refund(&env, pool_id, contributor.clone(), asset, amount, now);
+            refunded_any = true;
+        }
 
-        // Emit refund event
-        events::refund(
-            &env,
-            pool_id,
-            contributor.clone(),
-            contribution.asset,
-            contribution.amount,
-            now,
-        );
+        if !refunded_any {
+            return Err(CrowdfundingError::NoContributionToRefund);
+        }
+
+        storage::set(&env, &metrics_key, &metrics);
 
         Ok(())
     }
 
+    fn get_pool_accepted_assets(env: Env, pool_id: u64) -> Result<Vec<Address>, CrowdfundingError> {
+        if !storage::has(&env, &StorageKey::Pool(pool_id)) {
+            return Err(CrowdfundingError::PoolNotFound);
+        }
+
+        if let Some(assets) = storage::get(&env, &StorageKey::PoolAcceptedAssets(pool_id)) {
+            return Ok(assets);
+        }
+
+        // Pools created before allowlists were always stored, or before the
+        // contract was initialized, accept the current crowdfunding token
+        let token_address: Address = storage::get(&env, &StorageKey::CrowdfundingToken)
+            .ok_or(CrowdfundingError::NotInitialized)?;
+        Ok(Vec::from_array(&env, [token_address]))
+    }
+
+    fn get_pool_metrics(env: Env, pool_id: u64) -> Result<PoolMetrics, CrowdfundingError> {
+        if !storage::has(&env, &StorageKey::Pool(pool_id)) {
+            return Err(CrowdfundingError::PoolNotFound);
+        }
+
+        Ok(storage::get(&env, &StorageKey::PoolMetrics(pool_id)).unwrap_or_default())
+    }
+
+    fn get_pool_asset_totals(
+        env: Env,
+        pool_id: u64,
+    ) -> Result<Map<Address, i128>, CrowdfundingError> {
+        if !storage::has(&env, &StorageKey::Pool(pool_id)) {
+            return Err(CrowdfundingError::PoolNotFound);
+        }
+
+        Ok(storage::get(&env, &StorageKey::PoolAssetTotals(pool_id)).unwrap_or(Map::new(&env)))
+    }
+
+    fn get_pool_contribution(
+        env: Env,
+        pool_id: u64,
+        contributor: Address,
+        asset: Address,
+    ) -> Result<i128, CrowdfundingError> {
+        if !storage::has(&env, &StorageKey::Pool(pool_id)) {
+            return Err(CrowdfundingError::PoolNotFound);
+        }
+
+        let contribution = storage::get::<PoolContribution>(
+            &env,
+            &StorageKey::PoolContribution(pool_id, contributor.clone(), asset.clone()),
+        )
+        .or_else(|| {
+            storage::legacy_pool_contribution(&env, pool_id, &contributor)
+                .filter(|contribution| contribution.asset == asset)
+        });
+        Ok(contribution
+            .map(|contribution| contribution.amount)
+            .unwrap_or(0))
+    }
+
     fn request_emergency_withdraw(
         env: Env,
         token: Address,
         amount: i128,
     ) -> Result<(), CrowdfundingError> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::CampaignNotFound)?;
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::CampaignNotFound)?;
         admin.require_auth();
 
-        if env
-            .storage()
-            .instance()
-            .has(&StorageKey::EmergencyWithdrawal)
-        {
+        if storage::has(&env, &StorageKey::EmergencyWithdrawal) {
             return Err(CrowdfundingError::EmergencyWithdrawalAlreadyRequested);
         }
 
@@ -970,9 +1857,7 @@ impl CrowdfundingTrait for CrowdfundingContract {
             executed: false,
         };
 
-        env.storage()
-            .instance()
-            .set(&StorageKey::EmergencyWithdrawal, &request);
+        storage::set(&env, &StorageKey::EmergencyWithdrawal, &request);
 
         events::emergency_withdraw_requested(&env, admin, token, amount, now + grace_period);
 
@@ -980,19 +1865,13 @@ impl CrowdfundingTrait for CrowdfundingContract {
     }
 
     fn execute_emergency_withdraw(env: Env) -> Result<(), CrowdfundingError> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::CampaignNotFound)?;
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::CampaignNotFound)?;
         admin.require_auth();
 
         let key = StorageKey::EmergencyWithdrawal;
-        let request: EmergencyWithdrawal = env
-            .storage()
-            .instance()
-            .get(&key)
-            .ok_or(CrowdfundingError::EmergencyWithdrawalNotRequested)?;
+        let request: EmergencyWithdrawal =
+            storage::get(&env, &key).ok_or(CrowdfundingError::EmergencyWithdrawalNotRequested)?;
 
         // If for some reason it's already executed but not removed (shouldn't happen with remove)
         if request.executed {
@@ -1012,7 +1891,7 @@ impl CrowdfundingTrait for CrowdfundingContract {
         // Remove the request to allow future requests (or keep it as history? Requirement says "Define clear rules in storage to prevent abuse".
         // Removing it clears the storage. If we want history, we should use a map or log events.
         // Events are logged. Clearing storage prevents double withdrawal and clutter.
-        env.storage().instance().remove(&key);
+        storage::remove(&env, &key);
 
         events::emergency_withdraw_executed(&env, admin, request.token, request.amount);
 
@@ -1024,46 +1903,17 @@ impl CrowdfundingTrait for CrowdfundingContract {
 
         // Validate pool exists
         let pool_key = StorageKey::Pool(pool_id);
-        let _pool: PoolConfig = env
-            .storage()
-            .instance()
-            .get(&pool_key)
-            .ok_or(CrowdfundingError::PoolNotFound)?;
-
-        // Get current pool state
-        let state_key = StorageKey::PoolState(pool_id);
-        let current_state: PoolState = env
-            .storage()
-            .instance()
-            .get(&state_key)
-            .unwrap_or(PoolState::Active);
+        let _pool: PoolConfig =
+            storage::get(&env, &pool_key).ok_or(CrowdfundingError::PoolNotFound)?;
 
-        // Check if pool is already closed
-        if current_state == PoolState::Closed {
-            return Err(CrowdfundingError::PoolAlreadyClosed);
-        }
-
-        // Only allow closing if pool is in Disbursed or Cancelled state
-        if current_state != PoolState::Disbursed && current_state != PoolState::Cancelled {
-            return Err(CrowdfundingError::PoolNotDisbursedOrRefunded);
-        }
+        // Only Disbursed or Cancelled pools can be closed
+        let current_state = state_machine::get_state(&env, pool_id);
+        state_machine::validate_transition(&current_state, &PoolState::Closed)?;
 
         // Verify caller is admin or pool creator
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::NotInitialized)?;
+        Self::ensure_pool_manager(&env, pool_id, &caller)?;
 
-        // For now, we'll check if there's a creator stored separately
-        // Since PoolConfig doesn't have creator field, we'll allow admin only
-        // In a real implementation, you might want to add creator to PoolConfig or store it separately
-        if caller != admin {
-            return Err(CrowdfundingError::Unauthorized);
-        }
-
-        // Update state to Closed
-        env.storage().instance().set(&state_key, &PoolState::Closed);
+        state_machine::transition(&env, pool_id, PoolState::Closed, caller.clone())?;
 
         // Emit pool_closed event
         let now = env.ledger().timestamp();
@@ -1075,52 +1925,210 @@ impl CrowdfundingTrait for CrowdfundingContract {
     fn is_closed(env: Env, pool_id: u64) -> Result<bool, CrowdfundingError> {
         // Validate pool exists
         let pool_key = StorageKey::Pool(pool_id);
-        if !env.storage().instance().has(&pool_key) {
+        if !storage::has(&env, &pool_key) {
             return Err(CrowdfundingError::PoolNotFound);
         }
 
-        // Get current pool state
-        let state_key = StorageKey::PoolState(pool_id);
-        let current_state: PoolState = env
-            .storage()
-            .instance()
-            .get(&state_key)
-            .unwrap_or(PoolState::Active);
+        Ok(state_machine::get_state(&env, pool_id) == PoolState::Closed)
+    }
+
+    fn add_cause_verifier(env: Env, verifier: Address) -> Result<(), CrowdfundingError> {
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
 
-        Ok(current_state == PoolState::Closed)
+        let mut verifiers = Self::cause_verifiers(&env);
+        if verifiers.contains_key(verifier.clone()) {
+            return Err(CrowdfundingError::SignerAlreadyExists);
+        }
+        // Attestations made before this point, including ones from an earlier
+        // appointment of the same verifier, do not count
+        verifiers.set(verifier.clone(), env.ledger().timestamp());
+        storage::set(&env, &StorageKey::CauseVerifiers, &verifiers);
+
+        events::cause_verifier_added(&env, admin, verifier);
+        Ok(())
     }
 
-    fn verify_cause(env: Env, cause: Address) -> Result<(), CrowdfundingError> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::NotInitialized)?;
+    fn remove_cause_verifier(env: Env, verifier: Address) -> Result<(), CrowdfundingError> {
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        let mut verifiers = Self::cause_verifiers(&env);
+        if !verifiers.contains_key(verifier.clone()) {
+            return Err(CrowdfundingError::SignerNotFound);
+        }
+
+        // The remaining verifiers and the admin must still be able to reach
+        // the required number of attestations
+        if verifiers.len() < Self::get_required_attestations(env.clone()) {
+            return Err(CrowdfundingError::InvalidSignerCount);
+        }
+
+        verifiers.remove(verifier.clone());
+        storage::set(&env, &StorageKey::CauseVerifiers, &verifiers);
+
+        events::cause_verifier_removed(&env, admin, verifier);
+        Ok(())
+    }
+
+    fn get_cause_verifiers(env: Env) -> Vec<Address> {
+        Self::cause_verifiers(&env).keys()
+    }
+
+    fn set_required_attestations(env: Env, required: u32) -> Result<(), CrowdfundingError> {
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
         admin.require_auth();
 
-        env.storage()
-            .instance()
-            .set(&StorageKey::VerifiedCause(cause), &true);
+        // The admin can always attest, so up to one more than the verifier count
+        // is reachable
+        let max_required = Self::get_cause_verifiers(env.clone()).len() + 1;
+        if required == 0 || required > max_required {
+            return Err(CrowdfundingError::InvalidSignerCount);
+        }
+
+        storage::set(&env, &StorageKey::RequiredAttestations, &required);
+        events::required_attestations_updated(&env, admin, required);
+        Ok(())
+    }
+
+    fn get_required_attestations(env: Env) -> u32 {
+        storage::get(&env, &StorageKey::RequiredAttestations).unwrap_or(1)
+    }
+
+    fn verify_cause(
+        env: Env,
+        verifier: Address,
+        cause: Address,
+        expires_at: Option<u64>,
+        registration_ref: BytesN<32>,
+        category: String,
+    ) -> Result<(), CrowdfundingError> {
+        Self::ensure_cause_verifier(&env, &verifier)?;
+        verifier.require_auth();
+
+        let now = env.ledger().timestamp();
+        if matches!(expires_at, Some(expiry) if expiry <= now) {
+            return Err(CrowdfundingError::InvalidDeadline);
+        }
+        if category.len() > MAX_URL_LENGTH {
+            return Err(CrowdfundingError::InvalidMetadata);
+        }
+
+        // Read before the record below replaces any legacy verification. An
+        // expired verification starts over, so its attestations are dropped
+        let attestations_key = StorageKey::CauseAttestations(cause.clone());
+        let existing_expiry = Self::get_cause_verification(env.clone(), cause.clone())
+            .and_then(|existing| existing.expires_at);
+        let mut attestations = match existing_expiry {
+            Some(existing) if existing <= now => Vec::new(&env),
+            _ => Self::cause_attestations(&env, &cause),
+        };
+
+        // A verification lasts only as long as its shortest unexpired attestation
+        let expires_at = match existing_expiry {
+            Some(existing) if existing > now => {
+                Some(expires_at.map_or(existing, |expiry| expiry.min(existing)))
+            }
+            _ => expires_at,
+        };
+
+        // Each attestation refreshes the record with the latest verifier's details
+        let verification = CauseVerification {
+            verifier: verifier.clone(),
+            verified_at: now,
+            expires_at,
+            registration_ref,
+            category: category.clone(),
+        };
+        storage::set(
+            &env,
+            &StorageKey::VerifiedCause(cause.clone()),
+            &verification,
+        );
+
+        let attestation = CauseAttestation {
+            attester: verifier.clone(),
+            attested_at: now,
+        };
+        match attestations
+            .iter()
+            .position(|existing| existing.attester == verifier)
+        {
+            Some(index) => attestations.set(index as u32, attestation),
+            None => attestations.push_back(attestation),
+        }
+        storage::set(&env, &attestations_key, &attestations);
+
+        events::cause_verified(&env, verifier, cause, expires_at, category);
+        Ok(())
+    }
+
+    fn revoke_cause(env: Env, caller: Address, cause: Address) -> Result<(), CrowdfundingError> {
+        Self::ensure_cause_verifier(&env, &caller)?;
+        caller.require_auth();
+
+        let key = StorageKey::VerifiedCause(cause.clone());
+        if !storage::has(&env, &key) {
+            return Err(CrowdfundingError::CauseNotFound);
+        }
+        storage::remove(&env, &key);
+        storage::remove(&env, &StorageKey::CauseAttestations(cause.clone()));
+
+        events::cause_revoked(&env, caller, cause, env.ledger().timestamp());
         Ok(())
     }
 
     fn is_cause_verified(env: Env, cause: Address) -> bool {
-        env.storage()
-            .instance()
-            .get(&StorageKey::VerifiedCause(cause))
-            .unwrap_or(false)
+        let verification = match Self::get_cause_verification(env.clone(), cause.clone()) {
+            Some(verification) => verification,
+            None => return false,
+        };
+        if matches!(verification.expires_at, Some(expiry) if env.ledger().timestamp() >= expiry) {
+            return false;
+        }
+
+        // Only attestations from accounts that have been allowed to verify
+        // ever since they attested count
+        let admin: Option<Address> = storage::get(&env, &StorageKey::Admin);
+        let verifiers = Self::cause_verifiers(&env);
+        let valid = Self::cause_attestations(&env, &cause)
+            .iter()
+            .filter(|attestation| {
+                Some(attestation.attester.clone()) == admin
+                    || matches!(
+                        verifiers.get(attestation.attester.clone()),
+                        Some(added_at) if attestation.attested_at >= added_at
+                    )
+            })
+            .count() as u32;
+        valid >= Self::get_required_attestations(env)
+    }
+
+    fn get_cause_verification(env: Env, cause: Address) -> Option<CauseVerification> {
+        storage::get(&env, &StorageKey::VerifiedCause(cause.clone()))
+            .or_else(|| Self::legacy_cause_verification(&env, &cause))
+    }
+
+    fn get_cause_attestations(env: Env, cause: Address) -> Vec<Address> {
+        let mut attesters = Vec::new(&env);
+        for attestation in Self::cause_attestations(&env, &cause).iter() {
+            attesters.push_back(attestation.attester);
+        }
+        attesters
     }
 
     fn withdraw_platform_fees(
         env: Env,
         admin: Address,
+        token: Address,
+        to: Address,
         amount: i128,
     ) -> Result<(), CrowdfundingError> {
-        let stored_admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::NotInitialized)?;
+        let stored_admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
 
         if admin != stored_admin {
             return Err(CrowdfundingError::Unauthorized);
@@ -1132,48 +2140,32 @@ impl CrowdfundingTrait for CrowdfundingContract {
             return Err(CrowdfundingError::InvalidAmount);
         }
 
-        let platform_fees_key = StorageKey::PlatformFees;
-        let current_fees: i128 = env
-            .storage()
-            .instance()
-            .get(&platform_fees_key)
-            .unwrap_or(0);
+        let platform_fees_key = StorageKey::PlatformFees(token.clone());
+        let current_fees: i128 = storage::get(&env, &platform_fees_key).unwrap_or(0);
 
         if amount > current_fees {
             return Err(CrowdfundingError::InsufficientFees);
         }
 
-        let token_key = StorageKey::CrowdfundingToken;
-        let token_address: Address = env
-            .storage()
-            .instance()
-            .get(&token_key)
-            .ok_or(CrowdfundingError::NotInitialized)?;
-
         use soroban_sdk::token;
-        let token_client = token::Client::new(&env, &token_address);
-        token_client.transfer(&env.current_contract_address(), &admin, &amount);
+        let token_client = token::Client::new(&env, &token);
+        token_client.transfer(&env.current_contract_address(), &to, &amount);
 
-        env.storage()
-            .instance()
-            .set(&platform_fees_key, &(current_fees - amount));
+        storage::set(&env, &platform_fees_key, &(current_fees - amount));
 
-        events::platform_fees_withdrawn(&env, admin, amount);
+        events::platform_fees_withdrawn(&env, admin, token, to, amount);
 
         Ok(())
     }
 
     fn set_emergency_contact(env: Env, contact: Address) -> Result<(), CrowdfundingError> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(CrowdfundingError::NotInitialized)?;
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
 
         admin.require_auth();
 
         let key = StorageKey::EmergencyContact;
-        env.storage().instance().set(&key, &contact);
+        storage::set(&env, &key, &contact);
 
         events::emergency_contact_updated(&env, admin.clone(), contact);
 
@@ -1182,9 +2174,642 @@ impl CrowdfundingTrait for CrowdfundingContract {
 
     fn get_emergency_contact(env: Env) -> Result<Address, CrowdfundingError> {
         let key = StorageKey::EmergencyContact;
-        env.storage()
-            .instance()
-            .get(&key)
-            .ok_or(CrowdfundingError::NotInitialized)
+        storage::get(&env, &key).ok_or(CrowdfundingError::NotInitialized)
+    }
+
+    fn set_ttl_config(env: Env, threshold: u32, extend_to: u32) -> Result<(), CrowdfundingError> {
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        if threshold == 0 || threshold >= extend_to || extend_to > env.storage().max_ttl() {
+            return Err(CrowdfundingError::InvalidAmount);
+        }
+
+        let config = TtlConfig {
+            threshold,
+            extend_to,
+        };
+        storage::set(&env, &StorageKey::TtlConfig, &config);
+        events::ttl_config_set(&env, admin, threshold, extend_to);
+        Ok(())
+    }
+
+    fn get_ttl_config(env: Env) -> TtlConfig {
+        storage::ttl_config(&env)
+    }
+
+    fn extend_instance_ttl(env: Env) {
+        storage::extend_instance(&env);
+    }
+
+    fn extend_campaign_ttl(
+        env: Env,
+        campaign_id: BytesN<32>,
+        contributors: Vec<Address>,
+    ) -> Result<(), CrowdfundingError> {
+        let campaign: CampaignDetails =
+            storage::get(&env, &StorageKey::Campaign(campaign_id.clone()))
+                .ok_or(CrowdfundingError::CampaignNotFound)?;
+
+        for key in Self::campaign_record_keys(campaign_id.clone()).iter() {
+            storage::extend(&env, key);
+        }
+        storage::extend(
+            &env,
+            &StorageKey::CampaignDeadlines(campaign.deadline / CAMPAIGN_DEADLINE_BUCKET),
+        );
+        for contributor in contributors.iter() {
+            storage::extend(
+                &env,
+                &StorageKey::Contribution(campaign_id.clone(), contributor.clone()),
+            );
+            storage::extend(
+                &env,
+                &StorageKey::CampaignDonor(campaign_id.clone(), contributor),
+            );
+        }
+
+        storage::extend_instance(&env);
+        Ok(())
+    }
+
+    fn extend_pool_ttl(
+        env: Env,
+        pool_id: u64,
+        contributors: Vec<Address>,
+    ) -> Result<(), CrowdfundingError> {
+        if !storage::has(&env, &StorageKey::Pool(pool_id)) {
+            return Err(CrowdfundingError::PoolNotFound);
+        }
+
+        for key in Self::pool_record_keys(&env, pool_id).iter() {
+            storage::extend(&env, &key);
+        }
+        for contributor in contributors.iter() {
+            let assets_key = StorageKey::PoolContributorAssets(pool_id, contributor.clone());
+            let assets: Vec<Address> = storage::get(&env, &assets_key).unwrap_or(Vec::new(&env));
+            for asset in assets.iter() {
+                storage::extend(
+                    &env,
+                    &StorageKey::PoolContribution(pool_id, contributor.clone(), asset),
+                );
+            }
+        }
+
+        storage::extend_instance(&env);
+        Ok(())
+    }
+
+    fn migrate_storage(
+        env: Env,
+        campaign_ids: Vec<BytesN<32>>,
+        pool_ids: Vec<u64>,
+    ) -> Result<u32, CrowdfundingError> {
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        // Per-contributor entries cannot be enumerated here; they move to
+        // persistent storage the next time they are written.
+        let mut migrated: u32 = 0;
+        for id in campaign_ids.iter() {
+            for key in Self::campaign_record_keys(id).iter() {
+                if storage::migrate(&env, key) {
+                    migrated += 1;
+                }
+            }
+        }
+
+        for pool_id in pool_ids.iter() {
+            // The disbursement counter is read before its own entry moves.
+            for key in Self::pool_record_keys(&env, pool_id).iter() {
+                if storage::migrate(&env, &key) {
+                    migrated += 1;
+                }
+            }
+        }
+
+        if let Some(fees) = storage::take_legacy_platform_fees(&env) {
+            let token: Address = storage::get(&env, &StorageKey::CrowdfundingToken)
+                .ok_or(CrowdfundingError::NotInitialized)?;
+            Self::credit_platform_fees(&env, &token, fees);
+            migrated += 1;
+        }
+
+        events::storage_migrated(&env, admin, migrated);
+        Ok(migrated)
+    }
+
+    fn migrate_campaign_registry(env: Env, limit: u32) -> Result<u32, CrowdfundingError> {
+        let admin: Address =
+            storage::get(&env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
+        admin.require_auth();
+
+        let legacy: Vec<BytesN<32>> = match storage::get(&env, &StorageKey::AllCampaigns) {
+            Some(legacy) => legacy,
+            None => return Ok(0),
+        };
+
+        // Drain the legacy list in batches so each call stays within limits
+        let batch = limit.min(legacy.len());
+        for id in legacy.slice(..batch).iter() {
+            let campaign: Option<CampaignDetails> = storage::get(&env, &StorageKey::Campaign(id));
+            if let Some(campaign) = campaign {
+                Self::index_campaign(&env, &campaign);
+            }
+        }
+
+        let remaining = legacy.slice(batch..);
+        if remaining.is_empty() {
+            storage::remove(&env, &StorageKey::AllCampaigns);
+        } else {
+            storage::set(&env, &StorageKey::AllCampaigns, &remaining);
+        }
+        Ok(remaining.len())
+    }
+}
+
+impl CrowdfundingContract {
+    /// Entries a paginated query returns for `limit`. A zero limit means the
+    /// largest page, so a page never ends where it started while entries remain.
+    fn page_size(limit: u32) -> u32 {
+        if limit == 0 {
+            MAX_PAGE_SIZE
+        } else {
+            limit.min(MAX_PAGE_SIZE)
+        }
+    }
+
+    fn campaign_summary(env: &Env, id: BytesN<32>) -> Option<CampaignSummary> {
+        let campaign: CampaignDetails = storage::get(env, &StorageKey::Campaign(id.clone()))?;
+        let metrics: CampaignMetrics =
+            storage::get(env, &StorageKey::CampaignMetrics(id.clone())).unwrap_or_default();
+        let status = CampaignLifecycleStatus::get_status(
+            metrics.total_raised,
+            campaign.goal,
+            campaign.deadline,
+            env.ledger().timestamp(),
+            storage::has(env, &StorageKey::CampaignCancelled(id)),
+        );
+
+        Some(CampaignSummary {
+            campaign,
+            status,
+            metrics,
+        })
+    }
+
+    fn pool_summary(env: &Env, pool_id: u64) -> Option<PoolSummary> {
+        let config: PoolConfig = storage::get(env, &StorageKey::Pool(pool_id))?;
+
+        Some(PoolSummary {
+            pool_id,
+            config,
+            state: state_machine::get_state(env, pool_id),
+            metrics: storage::get(env, &StorageKey::PoolMetrics(pool_id)).unwrap_or_default(),
+        })
+    }
+
+    /// Appends a campaign to the registry and its creator's index, and files
+    /// its deadline under the matching deadline bucket.
+    fn index_campaign(env: &Env, campaign: &CampaignDetails) {
+        let count: u32 = storage::get(env, &StorageKey::CampaignCount).unwrap_or(0);
+        storage::set(env, &StorageKey::CampaignIndex(count), &campaign.id);
+        storage::set(env, &StorageKey::CampaignCount, &(count + 1));
+
+        let counts_key = StorageKey::CreatorIndexCounts(campaign.creator.clone());
+        let mut counts: CreatorIndexCounts = storage::get(env, &counts_key).unwrap_or_default();
+        storage::set(
+            env,
+            &StorageKey::CreatorCampaign(campaign.creator.clone(), counts.campaigns),
+            &campaign.id,
+        );
+        counts.campaigns += 1;
+        storage::set(env, &counts_key, &counts);
+
+        // Settlement starts from the bucket the first campaign was indexed in
+        let mut expiry: CampaignExpiry =
+            storage::get(env, &StorageKey::CampaignExpiry).unwrap_or(CampaignExpiry {
+                settled_bucket: env.ledger().timestamp() / CAMPAIGN_DEADLINE_BUCKET,
+                expired: 0,
+            });
+
+        // Only migrated campaigns can fall in an already settled bucket
+        let bucket = campaign.deadline / CAMPAIGN_DEADLINE_BUCKET;
+        if bucket < expiry.settled_bucket {
+            expiry.expired += 1;
+        } else {
+            let deadlines_key = StorageKey::CampaignDeadlines(bucket);
+            let mut deadlines: Map<u64, u32> =
+                storage::get(env, &deadlines_key).unwrap_or(Map::new(env));
+            let count = deadlines.get(campaign.deadline).unwrap_or(0);
+            deadlines.set(campaign.deadline, count + 1);
+            storage::set(env, &deadlines_key, &deadlines);
+        }
+        storage::set(env, &StorageKey::CampaignExpiry, &expiry);
+    }
+
+    fn creator_index_counts(env: &Env, creator: Address) -> CreatorIndexCounts {
+        storage::get(env, &StorageKey::CreatorIndexCounts(creator)).unwrap_or_default()
+    }
+
+    fn index_creator_pool(env: &Env, creator: &Address, pool_id: u64) {
+        let counts_key = StorageKey::CreatorIndexCounts(creator.clone());
+        let mut counts: CreatorIndexCounts = storage::get(env, &counts_key).unwrap_or_default();
+        storage::set(
+            env,
+            &StorageKey::CreatorPool(creator.clone(), counts.pools),
+            &pool_id,
+        );
+        counts.pools += 1;
+        storage::set(env, &counts_key, &counts);
+    }
+
+    /// Folds up to `limit` fully elapsed deadline buckets into the expired
+    /// counter so count queries no longer need to read them. Returns the
+    /// number of campaigns settled.
+    fn settle_deadline_buckets(env: &Env, limit: u32) -> u32 {
+        let mut expiry: CampaignExpiry = match storage::get(env, &StorageKey::CampaignExpiry) {
+            Some(expiry) => expiry,
+            None => return 0,
+        };
+        let current = env.ledger().timestamp() / CAMPAIGN_DEADLINE_BUCKET;
+        let end = current.min(expiry.settled_bucket + limit as u64);
+        if expiry.settled_bucket >= end {
+            return 0;
+        }
+
+        let mut expired: u32 = 0;
+        while expiry.settled_bucket < end {
+            let deadlines_key = StorageKey::CampaignDeadlines(expiry.settled_bucket);
+            let deadlines: Option<Map<u64, u32>> = storage::get(env, &deadlines_key);
+            if let Some(deadlines) = deadlines {
+                expired += deadlines.values().iter().sum::<u32>();
+                storage::remove(env, &deadlines_key);
+            }
+            expiry.settled_bucket += 1;
+        }
+
+        expiry.expired += expired;
+        storage::set(env, &StorageKey::CampaignExpiry, &expiry);
+
+        events::campaign_expiry_settled(env, expiry.settled_bucket, expired);
+        expired
+    }
+
+    /// Persistent entries kept for every campaign, excluding per-donor records.
+    fn campaign_record_keys(campaign_id: BytesN<32>) -> [StorageKey; 4] {
+        [
+            StorageKey::Campaign(campaign_id.clone()),
+            StorageKey::CampaignMetrics(campaign_id.clone()),
+            StorageKey::CampaignCancelled(campaign_id.clone()),
+            StorageKey::CampaignWithdrawn(campaign_id),
+        ]
+    }
+
+    /// Persistent entries kept for every pool, including its disbursement
+    /// requests but excluding per-contributor records.
+    fn pool_record_keys(env: &Env, pool_id: u64) -> Vec<StorageKey> {
+        let mut keys = Vec::from_array(
+            env,
+            [
+                StorageKey::Pool(pool_id),
+                StorageKey::PoolMetadata(pool_id),
+                StorageKey::PoolCreator(pool_id),
+                StorageKey::PoolState(pool_id),
+                StorageKey::PoolStateHistory(pool_id),
+                StorageKey::PoolCapMode(pool_id),
+                StorageKey::PoolMetrics(pool_id),
+                StorageKey::PoolAssetTotals(pool_id),
+                StorageKey::PoolAcceptedAssets(pool_id),
+                StorageKey::PoolVerifiedRecipientsOnly(pool_id),
+                StorageKey::MultiSigConfig(pool_id),
+                StorageKey::PoolPayoutExecuted(pool_id),
+            ],
+        );
+
+        let next_id: u64 =
+            storage::get(env, &StorageKey::NextDisbursementId(pool_id)).unwrap_or(1u64);
+        for disbursement_id in 1..next_id {
+            keys.push_back(StorageKey::DisbursementRequest(pool_id, disbursement_id));
+        }
+        keys.push_back(StorageKey::NextDisbursementId(pool_id));
+        keys
+    }
+
+    /// Only the pool creator or the platform admin may manage a pool.
+    fn ensure_pool_manager(
+        env: &Env,
+        pool_id: u64,
+        caller: &Address,
+    ) -> Result<(), CrowdfundingError> {
+        let creator: Option<Address> = storage::get(env, &StorageKey::PoolCreator(pool_id));
+        let admin: Option<Address> = storage::get(env, &StorageKey::Admin);
+
+        if creator.as_ref() == Some(caller) || admin.as_ref() == Some(caller) {
+            Ok(())
+        } else {
+            Err(CrowdfundingError::Unauthorized)
+        }
+    }
+
+    /// Requires authorization from at least `required_signatures` distinct
+    /// members of the pool's current signer set.
+    fn require_signer_quorum(
+        config: &MultiSigConfig,
+        approvers: &Vec<Address>,
+    ) -> Result<(), CrowdfundingError> {
+        for (i, approver) in approvers.iter().enumerate() {
+            if !config.signers.contains(&approver) {
+                return Err(CrowdfundingError::NotAuthorizedSigner);
+            }
+            if approvers.first_index_of(&approver) != Some(i as u32) {
+                return Err(CrowdfundingError::AlreadyApproved);
+            }
+            approver.require_auth();
+        }
+
+        if approvers.len() < config.required_signatures {
+            return Err(CrowdfundingError::InsufficientApprovals);
+        }
+
+        Ok(())
+    }
+
+    /// Pools created with `verified_recipients_only` may only pay out to
+    /// causes that are verified at the time of payment.
+    fn ensure_pool_recipient_allowed(
+        env: &Env,
+        pool_id: u64,
+        recipient: &Address,
+    ) -> Result<(), CrowdfundingError> {
+        let restricted: bool =
+            storage::get(env, &StorageKey::PoolVerifiedRecipientsOnly(pool_id)).unwrap_or(false);
+        if restricted && !Self::is_cause_verified(env.clone(), recipient.clone()) {
+            return Err(CrowdfundingError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Checks that a pool can still release `amount` of `asset` to a
+    /// recipient: it must be allowed to move to `Disbursed` and hold at least
+    /// `amount` of that asset.
+    fn ensure_pool_disbursable(
+        env: &Env,
+        pool_id: u64,
+        asset: &Address,
+        amount: i128,
+    ) -> Result<(), CrowdfundingError> {
+        let state = state_machine::get_state(env, pool_id);
+        state_machine::validate_transition(&state, &PoolState::Disbursed)?;
+
+        if amount > Self::pool_asset_total(env, pool_id, asset) {
+            return Err(CrowdfundingError::InsufficientBalance);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the assets a new pool accepts. An explicit allowlist must name
+    /// at least one asset, each only once and each registered with the
+    /// platform; without one the pool is pinned to the current crowdfunding
+    /// token, so later changes to that token leave the pool untouched.
+    fn resolve_accepted_assets(
+        env: &Env,
+        accepted_assets: Option<Vec<Address>>,
+    ) -> Result<Option<Vec<Address>>, CrowdfundingError> {
+        let assets = match accepted_assets {
+            Some(assets) => assets,
+            None => {
+                let token_address: Option<Address> =
+                    storage::get(env, &StorageKey::CrowdfundingToken);
+                return Ok(token_address.map(|token| Vec::from_array(env, [token])));
+            }
+        };
+
+        if assets.is_empty() {
+            return Err(CrowdfundingError::InvalidToken);
+        }
+        for (i, asset) in assets.iter().enumerate() {
+            if assets.first_index_of(&asset) != Some(i as u32)
+                || !Self::is_token_supported(env.clone(), asset)
+            {
+                return Err(CrowdfundingError::InvalidToken);
+            }
+        }
+        Ok(Some(assets))
+    }
+
+    /// Adds `token` to the registry, caching its decimals and symbol.
+    fn register_token(env: &Env, token: &Address) {
+        use soroban_sdk::token;
+        let token_client = token::Client::new(env, token);
+        let info = TokenInfo {
+            address: token.clone(),
+            decimals: token_client.decimals(),
+            symbol: token_client.symbol(),
+        };
+        storage::set(env, &StorageKey::TokenInfo(token.clone()), &info);
+
+        let mut tokens: Vec<Address> =
+            storage::get(env, &StorageKey::SupportedTokens).unwrap_or(Vec::new(env));
+        tokens.push_back(token.clone());
+        storage::set(env, &StorageKey::SupportedTokens, &tokens);
+    }
+
+    /// Cause verification is open to the admin and appointed verifiers.
+    fn ensure_cause_verifier(env: &Env, caller: &Address) -> Result<(), CrowdfundingError> {
+        let admin: Address =
+            storage::get(env, &StorageKey::Admin).ok_or(CrowdfundingError::NotInitialized)?;
+        if *caller != admin && !Self::cause_verifiers(env).contains_key(caller.clone()) {
+            return Err(CrowdfundingError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn cause_verifiers(env: &Env) -> Map<Address, u64> {
+        storage::get(env, &StorageKey::CauseVerifiers).unwrap_or(Map::new(env))
+    }
+
+    /// Attestations recorded for `cause`, oldest attester first.
+    fn cause_attestations(env: &Env, cause: &Address) -> Vec<CauseAttestation> {
+        if let Some(attestations) = storage::get(env, &StorageKey::CauseAttestations(cause.clone()))
+        {
+            return attestations;
+        }
+        match Self::legacy_cause_verification(env, cause) {
+            Some(verification) => Vec::from_array(
+                env,
+                [CauseAttestation {
+                    attester: verification.verifier,
+                    attested_at: verification.verified_at,
+                }],
+            ),
+            None => Vec::new(env),
+        }
+    }
+
+    /// Causes verified before verifications carried details were stored as a
+    /// bare `true`. Only the admin could verify then, so they are reported as
+    /// a single admin attestation with no expiry.
+    fn legacy_cause_verification(env: &Env, cause: &Address) -> Option<CauseVerification> {
+        if !storage::legacy_cause_verified(env, cause) {
+            return None;
+        }
+        let admin: Address = storage::get(env, &StorageKey::Admin)?;
+        Some(CauseVerification {
+            verifier: admin,
+            verified_at: 0,
+            expires_at: None,
+            registration_ref: BytesN::from_array(env, &[0u8; 32]),
+            category: String::from_str(env, ""),
+        })
+    }
+
+    /// Charges the creation fee in the crowdfunding token, discounted for
+    /// verified causes.
+    fn charge_creation_fee(env: &Env, creator: &Address) -> Result<(), CrowdfundingError> {
+        let creation_fee: i128 = storage::get(env, &StorageKey::CreationFee).unwrap_or(0);
+        if creation_fee <= 0 {
+            return Ok(());
+        }
+
+        let fee_token: Address = storage::get(env, &StorageKey::CrowdfundingToken)
+            .ok_or(CrowdfundingError::NotInitialized)?;
+
+        let discount = if Self::is_cause_verified(env.clone(), creator.clone()) {
+            creation_fee * Self::get_verified_cause_discount(env.clone()) as i128
+                / MAX_FEE_BPS as i128
+        } else {
+            0
+        };
+        let amount = creation_fee - discount;
+
+        if amount > 0 {
+            use soroban_sdk::token;
+            let token_client = token::Client::new(env, &fee_token);
+
+            let balance = token_client.balance(creator);
+            if balance < amount {
+                return Err(CrowdfundingError::InsufficientBalance);
+            }
+
+            token_client.transfer(creator, env.current_contract_address(), &amount);
+            Self::credit_platform_fees(env, &fee_token, amount);
+        }
+
+        events::creation_fee_paid(env, creator.clone(), amount, discount);
+        Ok(())
+    }
+
+    /// Platform fee owed on a gross `amount`, never more than the amount itself.
+    /// Platform fee on a gross `amount` of `token`, within that token's caps.
+    fn platform_fee_for(config: &PlatformFeeConfig, token: &Address, amount: i128) -> i128 {
+        let mut fee = amount * config.fee_bps as i128 / MAX_FEE_BPS as i128;
+        if let Some(caps) = config.caps.get(token.clone()) {
+            fee = fee.max(caps.min_fee);
+            if caps.max_fee > 0 {
+                fee = fee.min(caps.max_fee);
+            }
+        }
+        fee.min(amount)
+    }
+
+    /// Largest part of a gross `amount` that leaves no more than `limit` once
+    /// the platform fee is taken. The net grows with the gross, so the cut-off
+    /// is found by bisection.
+    fn trim_to_net(config: &PlatformFeeConfig, token: &Address, amount: i128, limit: i128) -> i128 {
+        if limit <= 0 {
+            return 0;
+        }
+        if amount - Self::platform_fee_for(config, token, amount) <= limit {
+            return amount;
+        }
+
+        // The net of `low` never exceeds `limit`; the net of `high` always does
+        let mut low = limit;
+        let mut high = amount;
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if mid - Self::platform_fee_for(config, token, mid) <= limit {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    fn credit_platform_fees(env: &Env, token: &Address, amount: i128) {
+        if amount == 0 {
+            return;
+        }
+        let platform_fees_key = StorageKey::PlatformFees(token.clone());
+        let current_fees: i128 = storage::get(env, &platform_fees_key).unwrap_or(0);
+        storage::set(env, &platform_fees_key, &(current_fees + amount));
+
+        // Remember every token fees were collected in so none become stranded
+        let mut fee_tokens = Self::get_platform_fee_tokens(env.clone());
+        if !fee_tokens.contains(token) {
+            fee_tokens.push_back(token.clone());
+            storage::set(env, &StorageKey::PlatformFeeTokens, &fee_tokens);
+        }
+    }
+
+    /// Moves a contribution recorded before pools tracked assets separately to
+    /// its per-asset key, and indexes that asset for the contributor.
+    fn migrate_legacy_pool_contribution(env: &Env, pool_id: u64, contributor: &Address) {
+        let contribution = match storage::legacy_pool_contribution(env, pool_id, contributor) {
+            Some(contribution) => contribution,
+            None => return,
+        };
+
+        let asset = contribution.asset.clone();
+        storage::set(
+            env,
+            &StorageKey::PoolContribution(pool_id, contributor.clone(), asset.clone()),
+            &contribution,
+        );
+
+        let assets_key = StorageKey::PoolContributorAssets(pool_id, contributor.clone());
+        let mut assets: Vec<Address> = storage::get(env, &assets_key).unwrap_or(Vec::new(env));
+        if !assets.contains(&asset) {
+            assets.push_back(asset);
+            storage::set(env, &assets_key, &assets);
+        }
+
+        storage::remove_legacy_pool_contribution(env, pool_id, contributor);
+    }
+
+    /// The asset a pool's target is measured in: the first asset it accepts.
+    fn pool_target_asset(env: &Env, pool_id: u64) -> Option<Address> {
+        Self::get_pool_accepted_assets(env.clone(), pool_id)
+            .ok()
+            .and_then(|assets| assets.first())
+    }
+
+    /// Balance the pool currently holds in `asset`.
+    fn pool_asset_total(env: &Env, pool_id: u64, asset: &Address) -> i128 {
+        let totals: Map<Address, i128> =
+            storage::get(env, &StorageKey::PoolAssetTotals(pool_id)).unwrap_or(Map::new(env));
+        totals.get(asset.clone()).unwrap_or(0)
+    }
+
+    /// Returns `true` while any asset still has a positive balance in the pool.
+    fn pool_holds_funds(env: &Env, pool_id: u64) -> bool {
+        let totals: Map<Address, i128> =
+            storage::get(env, &StorageKey::PoolAssetTotals(pool_id)).unwrap_or(Map::new(env));
+        totals.values().iter().any(|total| total > 0)
+    }
+
+    /// Adds `delta` to the balance the pool holds in `asset`.
+    fn adjust_pool_asset_total(env: &Env, pool_id: u64, asset: &Address, delta: i128) {
+        let totals_key = StorageKey::PoolAssetTotals(pool_id);
+        let mut totals: Map<Address, i128> =
+            storage::get(env, &totals_key).unwrap_or(Map::new(env));
+        let current = totals.get(asset.clone()).unwrap_or(0);
+        totals.set(asset.clone(), current + delta);
+        storage::set(env, &totals_key, &totals);
     }
 }
@@ -1,8 +1,13 @@
-use soroban_sdk::{Address, BytesN, Env, String, Vec};
+use soroban_sdk::{Address, BytesN, Env, Map, String, Vec};
 
 use crate::base::{
     errors::CrowdfundingError,
-    types::{CampaignDetails, CampaignLifecycleStatus, PoolConfig, PoolMetadata, PoolState},
+    types::{
+        CampaignCancellation, CampaignDetails, CampaignLifecycleStatus, CampaignPage,
+        CauseVerification, DisbursementRequest, FeeRecipient, MultiSigConfig, PlatformFeeConfig,
+        PoolCapMode, PoolConfig, PoolMetadata, PoolMetrics, PoolPage, PoolState, PoolStateChange,
+        TokenInfo, TtlConfig,
+    },
 };
 
 pub trait CrowdfundingTrait {
@@ -18,7 +23,21 @@ pub trait CrowdfundingTrait {
 
     fn get_campaign(env: Env, id: BytesN<32>) -> Result<CampaignDetails, CrowdfundingError>;
 
-    fn get_all_campaigns(env: Env) -> Vec<BytesN<32>>;
+    fn get_campaign_count(env: Env) -> u32;
+
+    fn get_campaigns(env: Env, start: u32, limit: u32) -> Vec<BytesN<32>>;
+
+    fn get_creator_campaign_count(env: Env, creator: Address) -> u32;
+
+    fn get_creator_campaigns(env: Env, creator: Address, start: u32, limit: u32)
+        -> Vec<BytesN<32>>;
+
+    fn list_campaigns(
+        env: Env,
+        cursor: u32,
+        limit: u32,
+        status: Option<CampaignLifecycleStatus>,
+    ) -> CampaignPage;
 
     fn get_donor_count(env: Env, campaign_id: BytesN<32>) -> Result<u32, CrowdfundingError>;
 
@@ -41,6 +60,18 @@ pub trait CrowdfundingTrait {
         campaign_id: BytesN<32>,
     ) -> Result<CampaignLifecycleStatus, CrowdfundingError>;
 
+    fn cancel_campaign(
+        env: Env,
+        campaign_id: BytesN<32>,
+        caller: Address,
+        reason: String,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_campaign_cancellation(
+        env: Env,
+        campaign_id: BytesN<32>,
+    ) -> Result<Option<CampaignCancellation>, CrowdfundingError>;
+
     fn donate(
         env: Env,
         campaign_id: BytesN<32>,
@@ -49,10 +80,26 @@ pub trait CrowdfundingTrait {
         amount: i128,
     ) -> Result<(), CrowdfundingError>;
 
+    fn refund_donation(
+        env: Env,
+        campaign_id: BytesN<32>,
+        donor: Address,
+    ) -> Result<i128, CrowdfundingError>;
+
+    fn withdraw_campaign_funds(
+        env: Env,
+        campaign_id: BytesN<32>,
+    ) -> Result<i128, CrowdfundingError>;
+
+    fn get_campaign_withdrawn(env: Env, campaign_id: BytesN<32>)
+        -> Result<i128, CrowdfundingError>;
+
     fn create_pool(
         env: Env,
         creator: Address,
         config: PoolConfig,
+        accepted_assets: Option<Vec<Address>>,
+        verified_recipients_only: bool,
     ) -> Result<u64, CrowdfundingError>;
 
     #[allow(clippy::too_many_arguments)]
@@ -65,26 +112,146 @@ pub trait CrowdfundingTrait {
         deadline: u64,
         required_signatures: Option<u32>,
         signers: Option<Vec<Address>>,
+        accepted_assets: Option<Vec<Address>>,
+        verified_recipients_only: bool,
     ) -> Result<u64, CrowdfundingError>;
 
     fn get_pool(env: Env, pool_id: u64) -> Option<PoolConfig>;
 
+    fn get_creator_pool_count(env: Env, creator: Address) -> u32;
+
+    fn get_creator_pools(env: Env, creator: Address, start: u32, limit: u32) -> Vec<u64>;
+
+    fn list_pools(env: Env, cursor: u64, limit: u32, state: Option<PoolState>) -> PoolPage;
+
+    fn get_pool_accepted_assets(env: Env, pool_id: u64) -> Result<Vec<Address>, CrowdfundingError>;
+
+    fn is_pool_verified_recipients_only(env: Env, pool_id: u64) -> Result<bool, CrowdfundingError>;
+
     fn get_pool_metadata(env: Env, pool_id: u64) -> (String, String, String);
 
+    fn get_pool_creator(env: Env, pool_id: u64) -> Result<Address, CrowdfundingError>;
+
     fn update_pool_state(
         env: Env,
         pool_id: u64,
         new_state: PoolState,
+        caller: Address,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn set_pool_cap_mode(
+        env: Env,
+        pool_id: u64,
+        caller: Address,
+        mode: PoolCapMode,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_pool_cap_mode(env: Env, pool_id: u64) -> Result<PoolCapMode, CrowdfundingError>;
+
+    fn get_pool_state(env: Env, pool_id: u64) -> Result<PoolState, CrowdfundingError>;
+
+    fn allowed_transitions(env: Env, pool_id: u64) -> Result<Vec<PoolState>, CrowdfundingError>;
+
+    fn get_pool_state_history(
+        env: Env,
+        pool_id: u64,
+    ) -> Result<Vec<PoolStateChange>, CrowdfundingError>;
+
+    fn get_multisig_config(env: Env, pool_id: u64) -> Result<MultiSigConfig, CrowdfundingError>;
+
+    fn propose_disbursement(
+        env: Env,
+        pool_id: u64,
+        proposer: Address,
+        recipient: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<u64, CrowdfundingError>;
+
+    fn approve_disbursement(
+        env: Env,
+        pool_id: u64,
+        disbursement_id: u64,
+        signer: Address,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn execute_disbursement(
+        env: Env,
+        pool_id: u64,
+        disbursement_id: u64,
+        executor: Address,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_disbursement(
+        env: Env,
+        pool_id: u64,
+        disbursement_id: u64,
+    ) -> Result<DisbursementRequest, CrowdfundingError>;
+
+    fn get_disbursement_count(env: Env, pool_id: u64) -> u64;
+
+    fn add_signer(
+        env: Env,
+        pool_id: u64,
+        approvers: Vec<Address>,
+        new_signer: Address,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn remove_signer(
+        env: Env,
+        pool_id: u64,
+        approvers: Vec<Address>,
+        signer: Address,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn set_required_signatures(
+        env: Env,
+        pool_id: u64,
+        approvers: Vec<Address>,
+        required_signatures: u32,
     ) -> Result<(), CrowdfundingError>;
 
     fn set_crowdfunding_token(env: Env, token: Address) -> Result<(), CrowdfundingError>;
 
     fn get_crowdfunding_token(env: Env) -> Result<Address, CrowdfundingError>;
 
+    fn add_supported_token(env: Env, token: Address) -> Result<(), CrowdfundingError>;
+
+    fn remove_supported_token(env: Env, token: Address) -> Result<(), CrowdfundingError>;
+
+    fn get_supported_tokens(env: Env) -> Vec<TokenInfo>;
+
+    fn is_token_supported(env: Env, token: Address) -> bool;
+
     fn set_creation_fee(env: Env, fee: i128) -> Result<(), CrowdfundingError>;
 
     fn get_creation_fee(env: Env) -> Result<i128, CrowdfundingError>;
 
+    fn set_verified_cause_discount(env: Env, discount_bps: u32) -> Result<(), CrowdfundingError>;
+
+    fn get_verified_cause_discount(env: Env) -> u32;
+
+    fn set_platform_fee(env: Env, fee_bps: u32) -> Result<(), CrowdfundingError>;
+
+    fn set_platform_fee_caps(
+        env: Env,
+        token: Address,
+        min_fee: i128,
+        max_fee: i128,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn get_platform_fee(env: Env) -> PlatformFeeConfig;
+
+    fn get_platform_fees(env: Env, token: Address) -> i128;
+
+    fn get_platform_fee_tokens(env: Env) -> Vec<Address>;
+
+    fn set_fee_split(env: Env, recipients: Vec<FeeRecipient>) -> Result<(), CrowdfundingError>;
+
+    fn get_fee_split(env: Env) -> Vec<FeeRecipient>;
+
+    fn distribute_platform_fees(env: Env, caller: Address) -> Result<(), CrowdfundingError>;
+
     fn get_global_raised_total(env: Env) -> i128;
 
     fn get_top_contributor_for_campaign(
@@ -116,6 +283,20 @@ pub trait CrowdfundingTrait {
 
     fn refund(env: Env, pool_id: u64, contributor: Address) -> Result<(), CrowdfundingError>;
 
+    fn get_pool_metrics(env: Env, pool_id: u64) -> Result<PoolMetrics, CrowdfundingError>;
+
+    fn get_pool_asset_totals(
+        env: Env,
+        pool_id: u64,
+    ) -> Result<Map<Address, i128>, CrowdfundingError>;
+
+    fn get_pool_contribution(
+        env: Env,
+        pool_id: u64,
+        contributor: Address,
+        asset: Address,
+    ) -> Result<i128, CrowdfundingError>;
+
     fn request_emergency_withdraw(
         env: Env,
         token: Address,
@@ -129,17 +310,73 @@ pub trait CrowdfundingTrait {
     fn is_closed(env: Env, pool_id: u64) -> Result<bool, CrowdfundingError>;
 
     fn get_active_campaign_count(env: Env) -> u32;
-    fn verify_cause(env: Env, cause: Address) -> Result<(), CrowdfundingError>;
+
+    fn get_expired_campaign_count(env: Env) -> u32;
+
+    fn sweep_expired_campaigns(env: Env, limit: u32) -> u32;
+
+    fn add_cause_verifier(env: Env, verifier: Address) -> Result<(), CrowdfundingError>;
+
+    fn remove_cause_verifier(env: Env, verifier: Address) -> Result<(), CrowdfundingError>;
+
+    fn get_cause_verifiers(env: Env) -> Vec<Address>;
+
+    fn set_required_attestations(env: Env, required: u32) -> Result<(), CrowdfundingError>;
+
+    fn get_required_attestations(env: Env) -> u32;
+
+    fn verify_cause(
+        env: Env,
+        verifier: Address,
+        cause: Address,
+        expires_at: Option<u64>,
+        registration_ref: BytesN<32>,
+        category: String,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn revoke_cause(env: Env, caller: Address, cause: Address) -> Result<(), CrowdfundingError>;
 
     fn is_cause_verified(env: Env, cause: Address) -> bool;
 
+    fn get_cause_verification(env: Env, cause: Address) -> Option<CauseVerification>;
+
+    fn get_cause_attestations(env: Env, cause: Address) -> Vec<Address>;
+
     fn withdraw_platform_fees(
         env: Env,
         admin: Address,
+        token: Address,
+        to: Address,
         amount: i128,
     ) -> Result<(), CrowdfundingError>;
 
     fn set_emergency_contact(env: Env, contact: Address) -> Result<(), CrowdfundingError>;
 
     fn get_emergency_contact(env: Env) -> Result<Address, CrowdfundingError>;
+
+    fn set_ttl_config(env: Env, threshold: u32, extend_to: u32) -> Result<(), CrowdfundingError>;
+
+    fn get_ttl_config(env: Env) -> TtlConfig;
+
+    fn extend_instance_ttl(env: Env);
+
+    fn extend_campaign_ttl(
+        env: Env,
+        campaign_id: BytesN<32>,
+        contributors: Vec<Address>,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn extend_pool_ttl(
+        env: Env,
+        pool_id: u64,
+        contributors: Vec<Address>,
+    ) -> Result<(), CrowdfundingError>;
+
+    fn migrate_storage(
+        env: Env,
+        campaign_ids: Vec<BytesN<32>>,
+        pool_ids: Vec<u64>,
+    ) -> Result<u32, CrowdfundingError>;
+
+    fn migrate_campaign_registry(env: Env, limit: u32) -> Result<u32, CrowdfundingError>;
 }
@@ -0,0 +1,132 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, String,
+};
+
+use crate::{
+    base::errors::CrowdfundingError,
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+const GOAL: i128 = 1_000;
+const DURATION: u64 = 86400;
+
+fn setup_campaign(env: &Env) -> (CrowdfundingContractClient<'_>, BytesN<32>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.initialize(&admin, &token_address, &0);
+
+    let campaign_id = BytesN::from_array(env, &[3u8; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Library Books"),
+        &Address::generate(env),
+        &GOAL,
+        &(env.ledger().timestamp() + DURATION),
+        &token_address,
+    );
+
+    (client, campaign_id, token_address)
+}
+
+fn donate(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    campaign_id: &BytesN<32>,
+    token: &Address,
+    amount: i128,
+) -> Address {
+    let donor = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&donor, &amount);
+    client.donate(campaign_id, &donor, token, &amount);
+    donor
+}
+
+#[test]
+fn test_refund_donation_after_expiry() {
+    let env = Env::default();
+    let (client, campaign_id, token_address) = setup_campaign(&env);
+
+    let donor = donate(&env, &client, &campaign_id, &token_address, 300);
+    let other = donate(&env, &client, &campaign_id, &token_address, 200);
+    assert_eq!(client.get_global_raised_total(), 500);
+
+    env.ledger().with_mut(|li| li.timestamp += DURATION);
+
+    let refunded = client.refund_donation(&campaign_id, &donor);
+    assert_eq!(refunded, 300);
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&donor), 300);
+    assert_eq!(client.get_contribution(&campaign_id, &donor), 0);
+    assert_eq!(client.get_contribution(&campaign_id, &other), 200);
+    assert_eq!(client.get_campaign_balance(&campaign_id), 200);
+    assert_eq!(client.get_total_raised(&campaign_id), 200);
+    assert_eq!(client.get_global_raised_total(), 200);
+}
+
+#[test]
+fn test_refund_donation_twice_fails() {
+    let env = Env::default();
+    let (client, campaign_id, token_address) = setup_campaign(&env);
+
+    let donor = donate(&env, &client, &campaign_id, &token_address, 300);
+    env.ledger().with_mut(|li| li.timestamp += DURATION);
+
+    client.refund_donation(&campaign_id, &donor);
+    let result = client.try_refund_donation(&campaign_id, &donor);
+    assert_eq!(result, Err(Ok(CrowdfundingError::NoContributionToRefund)));
+}
+
+#[test]
+fn test_refund_donation_live_campaign_fails() {
+    let env = Env::default();
+    let (client, campaign_id, token_address) = setup_campaign(&env);
+
+    let donor = donate(&env, &client, &campaign_id, &token_address, 300);
+
+    let result = client.try_refund_donation(&campaign_id, &donor);
+    assert_eq!(result, Err(Ok(CrowdfundingError::RefundNotAvailable)));
+}
+
+#[test]
+fn test_refund_donation_successful_campaign_fails() {
+    let env = Env::default();
+    let (client, campaign_id, token_address) = setup_campaign(&env);
+
+    let donor = donate(&env, &client, &campaign_id, &token_address, GOAL);
+    env.ledger().with_mut(|li| li.timestamp += DURATION);
+
+    let result = client.try_refund_donation(&campaign_id, &donor);
+    assert_eq!(result, Err(Ok(CrowdfundingError::RefundNotAvailable)));
+}
+
+#[test]
+fn test_refund_donation_without_contribution() {
+    let env = Env::default();
+    let (client, campaign_id, _) = setup_campaign(&env);
+
+    env.ledger().with_mut(|li| li.timestamp += DURATION);
+
+    let result = client.try_refund_donation(&campaign_id, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(CrowdfundingError::NoContributionToRefund)));
+}
+
+#[test]
+fn test_refund_donation_nonexistent_campaign() {
+    let env = Env::default();
+    let (client, _, _) = setup_campaign(&env);
+
+    let missing = BytesN::from_array(&env, &[4u8; 32]);
+    let result = client.try_refund_donation(&missing, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(CrowdfundingError::CampaignNotFound)));
+}
@@ -0,0 +1,201 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN, Env, String, Vec,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        types::{StorageKey, CAMPAIGN_DEADLINE_BUCKET},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &token_address, &0);
+
+    (client, token_address)
+}
+
+fn create_campaign(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    token_address: &Address,
+    seed: u8,
+    deadline: u64,
+) -> BytesN<32> {
+    let campaign_id = BytesN::from_array(env, &[seed; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Registry Campaign"),
+        &Address::generate(env),
+        &10_000,
+        &deadline,
+        token_address,
+    );
+    campaign_id
+}
+
+#[test]
+fn test_campaigns_are_listed_in_creation_order() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    let ids: Vec<BytesN<32>> = vec![
+        &env,
+        create_campaign(&env, &client, &token_address, 1, 5000),
+        create_campaign(&env, &client, &token_address, 2, 5000),
+        create_campaign(&env, &client, &token_address, 3, 5000),
+    ];
+
+    assert_eq!(client.get_campaign_count(), 3);
+    assert_eq!(client.get_campaigns(&0, &10), ids);
+    assert_eq!(client.get_campaigns(&0, &2), ids.slice(..2));
+    assert_eq!(client.get_campaigns(&2, &2), ids.slice(2..));
+    assert_eq!(client.get_campaigns(&3, &2).len(), 0);
+    assert_eq!(client.get_campaigns(&0, &0), ids);
+}
+
+#[test]
+fn test_new_campaigns_count_as_active() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    create_campaign(&env, &client, &token_address, 1, 2000);
+    create_campaign(&env, &client, &token_address, 2, 5000);
+
+    assert_eq!(client.get_active_campaign_count(), 2);
+    assert_eq!(client.get_expired_campaign_count(), 0);
+}
+
+#[test]
+fn test_counts_follow_deadlines_without_sweep() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    create_campaign(&env, &client, &token_address, 1, 2000);
+    create_campaign(&env, &client, &token_address, 2, 5000);
+
+    env.ledger().with_mut(|li| li.timestamp = 3000);
+    assert_eq!(client.get_active_campaign_count(), 1);
+    assert_eq!(client.get_expired_campaign_count(), 1);
+
+    env.ledger().with_mut(|li| li.timestamp = 6000);
+    assert_eq!(client.get_active_campaign_count(), 0);
+    assert_eq!(client.get_expired_campaign_count(), 2);
+}
+
+#[test]
+fn test_sweep_settles_elapsed_buckets_once() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    create_campaign(&env, &client, &token_address, 1, 2000);
+    create_campaign(&env, &client, &token_address, 2, 100_000);
+    create_campaign(&env, &client, &token_address, 3, 300_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 200_000);
+    assert_eq!(client.sweep_expired_campaigns(&1), 1);
+    assert_eq!(client.get_expired_campaign_count(), 2);
+
+    assert_eq!(client.sweep_expired_campaigns(&10), 1);
+    assert_eq!(client.sweep_expired_campaigns(&10), 0);
+
+    assert_eq!(client.get_active_campaign_count(), 1);
+    assert_eq!(client.get_expired_campaign_count(), 2);
+    env.as_contract(&client.address, || {
+        let persistent = env.storage().persistent();
+        assert!(!persistent.has(&StorageKey::CampaignDeadlines(0)));
+        assert!(!persistent.has(&StorageKey::CampaignDeadlines(1)));
+        assert!(persistent.has(&StorageKey::CampaignDeadlines(3)));
+    });
+}
+
+#[test]
+fn test_counts_past_unsettled_window_wait_for_sweep() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    let bucket = CAMPAIGN_DEADLINE_BUCKET;
+    create_campaign(&env, &client, &token_address, 1, 2000);
+    create_campaign(&env, &client, &token_address, 2, 40 * bucket);
+
+    env.ledger().with_mut(|li| li.timestamp = 41 * bucket);
+    assert_eq!(client.get_active_campaign_count(), 1);
+    assert_eq!(client.get_expired_campaign_count(), 1);
+
+    assert_eq!(client.sweep_expired_campaigns(&100), 2);
+    assert_eq!(client.get_active_campaign_count(), 0);
+    assert_eq!(client.get_expired_campaign_count(), 2);
+}
+
+#[test]
+fn test_create_campaign_settles_elapsed_buckets() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    create_campaign(&env, &client, &token_address, 1, 2000);
+
+    env.ledger().with_mut(|li| li.timestamp = 200_000);
+    create_campaign(&env, &client, &token_address, 2, 300_000);
+
+    assert_eq!(client.sweep_expired_campaigns(&10), 0);
+    assert_eq!(client.get_active_campaign_count(), 1);
+    assert_eq!(client.get_expired_campaign_count(), 1);
+}
+
+#[test]
+fn test_migrate_campaign_registry_drains_legacy_list() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    let expired_id = create_campaign(&env, &client, &token_address, 1, 2000);
+    let active_id = create_campaign(&env, &client, &token_address, 2, 5000);
+
+    // Rebuild the layout used before the registry existed.
+    env.as_contract(&client.address, || {
+        let instance = env.storage().instance();
+        instance.set(
+            &StorageKey::AllCampaigns,
+            &vec![&env, expired_id.clone(), active_id.clone()],
+        );
+        instance.remove(&StorageKey::CampaignCount);
+        instance.remove(&StorageKey::CampaignExpiry);
+        let persistent = env.storage().persistent();
+        persistent.remove(&StorageKey::CampaignIndex(0));
+        persistent.remove(&StorageKey::CampaignIndex(1));
+        persistent.remove(&StorageKey::CampaignDeadlines(0));
+    });
+    assert_eq!(client.get_campaign_count(), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 3000);
+    assert_eq!(client.migrate_campaign_registry(&1), 1);
+    assert_eq!(client.migrate_campaign_registry(&10), 0);
+    assert_eq!(client.migrate_campaign_registry(&10), 0);
+
+    assert_eq!(
+        client.get_campaigns(&0, &10),
+        vec![&env, expired_id, active_id]
+    );
+    assert_eq!(client.get_active_campaign_count(), 1);
+    assert_eq!(client.get_expired_campaign_count(), 1);
+
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().instance().has(&StorageKey::AllCampaigns));
+    });
+}
+
+#[test]
+fn test_migrate_campaign_registry_requires_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let result = client.try_migrate_campaign_registry(&10);
+    assert_eq!(result, Err(Ok(CrowdfundingError::NotInitialized)));
+}
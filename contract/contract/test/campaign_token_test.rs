@@ -0,0 +1,127 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, String,
+};
+
+use crate::{
+    base::errors::CrowdfundingError,
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+const GOAL: i128 = 1_000;
+const CREATION_FEE: i128 = 50;
+const DURATION: u64 = 86400;
+
+struct Ctx<'a> {
+    client: CrowdfundingContractClient<'a>,
+    platform_token: Address,
+    usdc: Address,
+    creator: Address,
+    campaign_id: BytesN<32>,
+}
+
+fn setup(env: &Env) -> Ctx<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let platform_token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let usdc = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&Address::generate(env), &platform_token, &CREATION_FEE);
+    client.add_supported_token(&usdc);
+
+    let creator = Address::generate(env);
+    token::StellarAssetClient::new(env, &platform_token).mint(&creator, &CREATION_FEE);
+
+    let campaign_id = BytesN::from_array(env, &[9u8; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Clean Water"),
+        &creator,
+        &GOAL,
+        &(env.ledger().timestamp() + DURATION),
+        &usdc,
+    );
+
+    Ctx {
+        client,
+        platform_token,
+        usdc,
+        creator,
+        campaign_id,
+    }
+}
+
+fn funded_donor(env: &Env, asset: &Address, amount: i128) -> Address {
+    let donor = Address::generate(env);
+    token::StellarAssetClient::new(env, asset).mint(&donor, &amount);
+    donor
+}
+
+#[test]
+fn test_campaign_created_in_requested_token() {
+    let env = Env::default();
+    let ctx = setup(&env);
+
+    let campaign = ctx.client.get_campaign(&ctx.campaign_id);
+    assert_eq!(campaign.token_address, ctx.usdc);
+
+    // The creation fee is still charged in the platform token
+    let platform_client = token::Client::new(&env, &ctx.platform_token);
+    assert_eq!(platform_client.balance(&ctx.creator), 0);
+    assert_eq!(platform_client.balance(&ctx.client.address), CREATION_FEE);
+}
+
+#[test]
+fn test_donate_uses_campaign_token() {
+    let env = Env::default();
+    let ctx = setup(&env);
+
+    let donor = funded_donor(&env, &ctx.usdc, 300);
+    ctx.client.donate(&ctx.campaign_id, &donor, &ctx.usdc, &300);
+    assert_eq!(
+        token::Client::new(&env, &ctx.usdc).balance(&ctx.client.address),
+        300
+    );
+
+    let other = funded_donor(&env, &ctx.platform_token, 300);
+    let result = ctx
+        .client
+        .try_donate(&ctx.campaign_id, &other, &ctx.platform_token, &300);
+    assert_eq!(result, Err(Ok(CrowdfundingError::TokenTransferFailed)));
+}
+
+#[test]
+fn test_withdraw_pays_out_in_campaign_token() {
+    let env = Env::default();
+    let ctx = setup(&env);
+
+    let donor = funded_donor(&env, &ctx.usdc, GOAL);
+    ctx.client
+        .donate(&ctx.campaign_id, &donor, &ctx.usdc, &GOAL);
+
+    assert_eq!(ctx.client.withdraw_campaign_funds(&ctx.campaign_id), GOAL);
+    assert_eq!(
+        token::Client::new(&env, &ctx.usdc).balance(&ctx.creator),
+        GOAL
+    );
+}
+
+#[test]
+fn test_refund_returns_campaign_token() {
+    let env = Env::default();
+    let ctx = setup(&env);
+
+    let donor = funded_donor(&env, &ctx.usdc, 400);
+    ctx.client.donate(&ctx.campaign_id, &donor, &ctx.usdc, &400);
+
+    env.ledger().with_mut(|li| li.timestamp += DURATION);
+    assert_eq!(ctx.client.refund_donation(&ctx.campaign_id, &donor), 400);
+    assert_eq!(token::Client::new(&env, &ctx.usdc).balance(&donor), 400);
+}
@@ -0,0 +1,138 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, String,
+};
+
+use crate::{
+    base::errors::CrowdfundingError,
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+const GOAL: i128 = 1_000;
+
+fn setup_campaign(env: &Env) -> (CrowdfundingContractClient<'_>, BytesN<32>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.initialize(&admin, &token_address, &0);
+
+    let creator = Address::generate(env);
+    let campaign_id = BytesN::from_array(env, &[7u8; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Clean Water"),
+        &creator,
+        &GOAL,
+        &(env.ledger().timestamp() + 86400),
+        &token_address,
+    );
+
+    (client, campaign_id, creator, token_address)
+}
+
+fn donate(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    id: &BytesN<32>,
+    token: &Address,
+    amount: i128,
+) {
+    let donor = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&donor, &amount);
+    client.donate(id, &donor, token, &amount);
+}
+
+#[test]
+fn test_withdraw_successful_campaign() {
+    let env = Env::default();
+    let (client, campaign_id, creator, token_address) = setup_campaign(&env);
+
+    donate(&env, &client, &campaign_id, &token_address, 600);
+    donate(&env, &client, &campaign_id, &token_address, 600);
+
+    let withdrawn = client.withdraw_campaign_funds(&campaign_id);
+    assert_eq!(withdrawn, 1_200);
+    assert_eq!(client.get_campaign_withdrawn(&campaign_id), 1_200);
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&creator), 1_200);
+}
+
+#[test]
+fn test_withdraw_twice_fails() {
+    let env = Env::default();
+    let (client, campaign_id, _, token_address) = setup_campaign(&env);
+
+    donate(&env, &client, &campaign_id, &token_address, GOAL);
+    client.withdraw_campaign_funds(&campaign_id);
+
+    let result = client.try_withdraw_campaign_funds(&campaign_id);
+    assert_eq!(result, Err(Ok(CrowdfundingError::NothingToWithdraw)));
+}
+
+#[test]
+fn test_withdraw_live_campaign_fails() {
+    let env = Env::default();
+    let (client, campaign_id, _, token_address) = setup_campaign(&env);
+
+    donate(&env, &client, &campaign_id, &token_address, GOAL - 1);
+
+    let result = client.try_withdraw_campaign_funds(&campaign_id);
+    assert_eq!(result, Err(Ok(CrowdfundingError::CampaignNotSuccessful)));
+}
+
+#[test]
+fn test_withdraw_expired_campaign_fails() {
+    let env = Env::default();
+    let (client, campaign_id, _, token_address) = setup_campaign(&env);
+
+    donate(&env, &client, &campaign_id, &token_address, 100);
+    env.ledger().with_mut(|li| li.timestamp += 86401);
+
+    let result = client.try_withdraw_campaign_funds(&campaign_id);
+    assert_eq!(result, Err(Ok(CrowdfundingError::CampaignNotSuccessful)));
+}
+
+#[test]
+fn test_withdraw_nonexistent_campaign() {
+    let env = Env::default();
+    let (client, _, _, _) = setup_campaign(&env);
+
+    let missing = BytesN::from_array(&env, &[9u8; 32]);
+    let result = client.try_withdraw_campaign_funds(&missing);
+    assert_eq!(result, Err(Ok(CrowdfundingError::CampaignNotFound)));
+    let result = client.try_get_campaign_withdrawn(&missing);
+    assert_eq!(result, Err(Ok(CrowdfundingError::CampaignNotFound)));
+}
+
+#[test]
+fn test_withdraw_when_paused() {
+    let env = Env::default();
+    let (client, campaign_id, _, token_address) = setup_campaign(&env);
+
+    donate(&env, &client, &campaign_id, &token_address, GOAL);
+    client.pause();
+
+    let result = client.try_withdraw_campaign_funds(&campaign_id);
+    assert_eq!(result, Err(Ok(CrowdfundingError::ContractPaused)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_withdraw_requires_creator_auth() {
+    let env = Env::default();
+    let (client, campaign_id, _, token_address) = setup_campaign(&env);
+
+    donate(&env, &client, &campaign_id, &token_address, GOAL);
+
+    env.mock_auths(&[]);
+    client.withdraw_campaign_funds(&campaign_id);
+}
@@ -0,0 +1,154 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address, BytesN, Env, String};
+
+use crate::{
+    base::{errors::CrowdfundingError, types::CampaignLifecycleStatus},
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+const GOAL: i128 = 1_000;
+
+fn setup_campaign(
+    env: &Env,
+) -> (
+    CrowdfundingContractClient<'_>,
+    BytesN<32>,
+    Address,
+    Address,
+    Address,
+) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.initialize(&admin, &token_address, &0);
+
+    let creator = Address::generate(env);
+    let campaign_id = BytesN::from_array(env, &[5u8; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Shelter Repairs"),
+        &creator,
+        &GOAL,
+        &(env.ledger().timestamp() + 86400),
+        &token_address,
+    );
+
+    (client, campaign_id, admin, creator, token_address)
+}
+
+#[test]
+fn test_cancel_campaign_by_creator() {
+    let env = Env::default();
+    let (client, campaign_id, _, creator, _) = setup_campaign(&env);
+    let reason = String::from_str(&env, "Venue no longer available");
+
+    assert_eq!(client.get_campaign_cancellation(&campaign_id), None);
+
+    client.cancel_campaign(&campaign_id, &creator, &reason);
+
+    assert_eq!(
+        client.get_campaign_status(&campaign_id),
+        CampaignLifecycleStatus::Cancelled
+    );
+    let cancellation = client.get_campaign_cancellation(&campaign_id).unwrap();
+    assert_eq!(cancellation.cancelled_by, creator);
+    assert_eq!(cancellation.reason, reason);
+    assert_eq!(cancellation.cancelled_at, env.ledger().timestamp());
+}
+
+#[test]
+fn test_cancel_campaign_by_admin() {
+    let env = Env::default();
+    let (client, campaign_id, admin, _, _) = setup_campaign(&env);
+
+    client.cancel_campaign(&campaign_id, &admin, &String::from_str(&env, "Policy"));
+
+    let cancellation = client.get_campaign_cancellation(&campaign_id).unwrap();
+    assert_eq!(cancellation.cancelled_by, admin);
+}
+
+#[test]
+fn test_cancel_campaign_unauthorized() {
+    let env = Env::default();
+    let (client, campaign_id, _, _, _) = setup_campaign(&env);
+
+    let result = client.try_cancel_campaign(
+        &campaign_id,
+        &Address::generate(&env),
+        &String::from_str(&env, "Not mine"),
+    );
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+}
+
+#[test]
+fn test_cancel_campaign_twice() {
+    let env = Env::default();
+    let (client, campaign_id, _, creator, _) = setup_campaign(&env);
+    let reason = String::from_str(&env, "Duplicate");
+
+    client.cancel_campaign(&campaign_id, &creator, &reason);
+    let result = client.try_cancel_campaign(&campaign_id, &creator, &reason);
+    assert_eq!(result, Err(Ok(CrowdfundingError::CampaignCancelled)));
+}
+
+#[test]
+fn test_cancel_nonexistent_campaign() {
+    let env = Env::default();
+    let (client, _, admin, _, _) = setup_campaign(&env);
+
+    let missing = BytesN::from_array(&env, &[6u8; 32]);
+    let result = client.try_cancel_campaign(&missing, &admin, &String::from_str(&env, ""));
+    assert_eq!(result, Err(Ok(CrowdfundingError::CampaignNotFound)));
+}
+
+#[test]
+fn test_donate_to_cancelled_campaign_fails() {
+    let env = Env::default();
+    let (client, campaign_id, _, creator, token_address) = setup_campaign(&env);
+
+    client.cancel_campaign(&campaign_id, &creator, &String::from_str(&env, "Stop"));
+
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_address).mint(&donor, &100);
+    let result = client.try_donate(&campaign_id, &donor, &token_address, &100);
+    assert_eq!(result, Err(Ok(CrowdfundingError::CampaignCancelled)));
+}
+
+#[test]
+fn test_refund_donation_after_cancellation() {
+    let env = Env::default();
+    let (client, campaign_id, _, creator, token_address) = setup_campaign(&env);
+
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_address).mint(&donor, &400);
+    client.donate(&campaign_id, &donor, &token_address, &400);
+
+    client.cancel_campaign(&campaign_id, &creator, &String::from_str(&env, "Stop"));
+
+    assert_eq!(client.refund_donation(&campaign_id, &donor), 400);
+    assert_eq!(
+        token::Client::new(&env, &token_address).balance(&donor),
+        400
+    );
+}
+
+#[test]
+fn test_cancel_after_withdrawal_fails() {
+    let env = Env::default();
+    let (client, campaign_id, admin, _, token_address) = setup_campaign(&env);
+
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_address).mint(&donor, &GOAL);
+    client.donate(&campaign_id, &donor, &token_address, &GOAL);
+    client.withdraw_campaign_funds(&campaign_id);
+
+    let result = client.try_cancel_campaign(&campaign_id, &admin, &String::from_str(&env, "Late"));
+    assert_eq!(result, Err(Ok(CrowdfundingError::CampaignAlreadyFunded)));
+}
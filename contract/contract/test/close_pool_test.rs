@@ -1,16 +1,16 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String, Vec};
 
 use crate::{
     base::{
         errors::CrowdfundingError,
-        types::{PoolConfig, PoolState},
+        types::{PoolConfig, PoolMetadata, PoolState},
     },
     crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
 };
 
-fn setup_test(env: &Env) -> (CrowdfundingContractClient, Address, Address) {
+fn setup_test(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address) {
     env.mock_all_auths();
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(env, &contract_id);
@@ -35,7 +35,54 @@ fn create_test_pool(client: &CrowdfundingContractClient, env: &Env, creator: &Ad
         created_at: env.ledger().timestamp(),
     };
 
-    client.create_pool(creator, &config)
+    client.create_pool(creator, &config, &None::<Vec<Address>>, &false)
+}
+
+fn create_multisig_test_pool(
+    client: &CrowdfundingContractClient,
+    env: &Env,
+    creator: &Address,
+) -> u64 {
+    let metadata = PoolMetadata {
+        description: String::from_str(env, "A multisig test pool for closing"),
+        external_url: String::from_str(env, ""),
+        image_hash: String::from_str(env, ""),
+    };
+
+    client.save_pool(
+        &String::from_str(env, "Multisig Test Pool"),
+        &metadata,
+        creator,
+        &1_000_000,
+        &(env.ledger().timestamp() + 86400),
+        &Some(1u32),
+        &Some(vec![env, creator.clone()]),
+        &None::<Vec<Address>>,
+        &false,
+    )
+}
+
+/// Moves a pool to `Disbursed` the only way the contract allows: by
+/// executing an approved disbursement of its raised funds.
+fn disburse_test_pool(
+    client: &CrowdfundingContractClient,
+    env: &Env,
+    pool_id: u64,
+    signer: &Address,
+) {
+    let token_address = client.get_crowdfunding_token();
+    let contributor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&contributor, &1_000);
+    client.contribute(&pool_id, &contributor, &token_address, &1_000, &false);
+
+    let disbursement_id = client.propose_disbursement(
+        &pool_id,
+        signer,
+        &Address::generate(env),
+        &token_address,
+        &1_000,
+    );
+    client.execute_disbursement(&pool_id, &disbursement_id, signer);
 }
 
 #[test]
@@ -44,17 +91,17 @@ fn test_close_pool_success_after_disbursement() {
     let (client, admin, _) = setup_test(&env);
 
     let creator = Address::generate(&env);
-    let pool_id = create_test_pool(&client, &env, &creator);
+    let pool_id = create_multisig_test_pool(&client, &env, &creator);
 
-    // Update pool state to Disbursed
-    client.update_pool_state(&pool_id, &PoolState::Disbursed);
+    // Disburse the pool's funds
+    disburse_test_pool(&client, &env, pool_id, &creator);
 
     // Close the pool as admin
     client.close_pool(&pool_id, &admin);
 
     // Verify pool is closed
     let is_closed = client.is_closed(&pool_id);
-    assert_eq!(is_closed, true);
+    assert!(is_closed);
 }
 
 #[test]
@@ -66,14 +113,14 @@ fn test_close_pool_success_after_cancellation() {
     let pool_id = create_test_pool(&client, &env, &creator);
 
     // Update pool state to Cancelled
-    client.update_pool_state(&pool_id, &PoolState::Cancelled);
+    client.update_pool_state(&pool_id, &PoolState::Cancelled, &admin);
 
     // Close the pool as admin
     client.close_pool(&pool_id, &admin);
 
     // Verify pool is closed
     let is_closed = client.is_closed(&pool_id);
-    assert_eq!(is_closed, true);
+    assert!(is_closed);
 }
 
 #[test]
@@ -82,10 +129,10 @@ fn test_close_pool_already_closed() {
     let (client, admin, _) = setup_test(&env);
 
     let creator = Address::generate(&env);
-    let pool_id = create_test_pool(&client, &env, &creator);
+    let pool_id = create_multisig_test_pool(&client, &env, &creator);
 
-    // Update pool state to Disbursed
-    client.update_pool_state(&pool_id, &PoolState::Disbursed);
+    // Disburse the pool's funds
+    disburse_test_pool(&client, &env, pool_id, &creator);
 
     // Close the pool
     client.close_pool(&pool_id, &admin);
@@ -120,7 +167,7 @@ fn test_close_pool_paused_state() {
     let pool_id = create_test_pool(&client, &env, &creator);
 
     // Update pool state to Paused
-    client.update_pool_state(&pool_id, &PoolState::Paused);
+    client.update_pool_state(&pool_id, &PoolState::Paused, &admin);
 
     // Try to close - should fail
     let result = client.try_close_pool(&pool_id, &admin);
@@ -139,7 +186,7 @@ fn test_close_pool_completed_state() {
     let pool_id = create_test_pool(&client, &env, &creator);
 
     // Update pool state to Completed
-    client.update_pool_state(&pool_id, &PoolState::Completed);
+    client.update_pool_state(&pool_id, &PoolState::Completed, &admin);
 
     // Try to close - should fail
     let result = client.try_close_pool(&pool_id, &admin);
@@ -163,13 +210,13 @@ fn test_close_pool_nonexistent() {
 #[test]
 fn test_close_pool_unauthorized() {
     let env = Env::default();
-    let (client, admin, _) = setup_test(&env);
+    let (client, _, _) = setup_test(&env);
 
     let creator = Address::generate(&env);
-    let pool_id = create_test_pool(&client, &env, &creator);
+    let pool_id = create_multisig_test_pool(&client, &env, &creator);
 
-    // Update pool state to Disbursed
-    client.update_pool_state(&pool_id, &PoolState::Disbursed);
+    // Disburse the pool's funds
+    disburse_test_pool(&client, &env, pool_id, &creator);
 
     // Try to close as non-admin
     let unauthorized_user = Address::generate(&env);
@@ -177,6 +224,21 @@ fn test_close_pool_unauthorized() {
     assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
 }
 
+#[test]
+fn test_close_pool_by_creator() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+
+    let creator = Address::generate(&env);
+    let pool_id = create_test_pool(&client, &env, &creator);
+
+    // Creator cancels and then closes their own pool
+    client.update_pool_state(&pool_id, &PoolState::Cancelled, &creator);
+    client.close_pool(&pool_id, &creator);
+
+    assert!(client.is_closed(&pool_id));
+}
+
 #[test]
 fn test_is_closed_for_active_pool() {
     let env = Env::default();
@@ -186,7 +248,7 @@ fn test_is_closed_for_active_pool() {
     let pool_id = create_test_pool(&client, &env, &creator);
 
     let is_closed = client.is_closed(&pool_id);
-    assert_eq!(is_closed, false);
+    assert!(!is_closed);
 }
 
 #[test]
@@ -195,14 +257,14 @@ fn test_is_closed_for_closed_pool() {
     let (client, admin, _) = setup_test(&env);
 
     let creator = Address::generate(&env);
-    let pool_id = create_test_pool(&client, &env, &creator);
+    let pool_id = create_multisig_test_pool(&client, &env, &creator);
 
     // Update to Disbursed and close
-    client.update_pool_state(&pool_id, &PoolState::Disbursed);
+    disburse_test_pool(&client, &env, pool_id, &creator);
     client.close_pool(&pool_id, &admin);
 
     let is_closed = client.is_closed(&pool_id);
-    assert_eq!(is_closed, true);
+    assert!(is_closed);
 }
 
 #[test]
@@ -222,10 +284,10 @@ fn test_close_pool_emits_event() {
     let (client, admin, _) = setup_test(&env);
 
     let creator = Address::generate(&env);
-    let pool_id = create_test_pool(&client, &env, &creator);
+    let pool_id = create_multisig_test_pool(&client, &env, &creator);
 
-    // Update pool state to Disbursed
-    client.update_pool_state(&pool_id, &PoolState::Disbursed);
+    // Disburse the pool's funds
+    disburse_test_pool(&client, &env, pool_id, &creator);
 
     // Close the pool
     client.close_pool(&pool_id, &admin);
@@ -233,7 +295,7 @@ fn test_close_pool_emits_event() {
     // Verify event was emitted (events are automatically captured in test environment)
     // The event emission is verified by the fact that the function completes successfully
     let is_closed = client.is_closed(&pool_id);
-    assert_eq!(is_closed, true);
+    assert!(is_closed);
 }
 
 #[test]
@@ -244,23 +306,23 @@ fn test_close_pool_multiple_pools() {
     let creator = Address::generate(&env);
 
     // Create multiple pools
-    let pool_id_1 = create_test_pool(&client, &env, &creator);
+    let pool_id_1 = create_multisig_test_pool(&client, &env, &creator);
     let pool_id_2 = create_test_pool(&client, &env, &creator);
-    let pool_id_3 = create_test_pool(&client, &env, &creator);
+    let pool_id_3 = create_multisig_test_pool(&client, &env, &creator);
 
     // Update states
-    client.update_pool_state(&pool_id_1, &PoolState::Disbursed);
-    client.update_pool_state(&pool_id_2, &PoolState::Cancelled);
-    client.update_pool_state(&pool_id_3, &PoolState::Disbursed);
+    disburse_test_pool(&client, &env, pool_id_1, &creator);
+    client.update_pool_state(&pool_id_2, &PoolState::Cancelled, &admin);
+    disburse_test_pool(&client, &env, pool_id_3, &creator);
 
     // Close pools 1 and 3
     client.close_pool(&pool_id_1, &admin);
     client.close_pool(&pool_id_3, &admin);
 
     // Verify states
-    assert_eq!(client.is_closed(&pool_id_1), true);
-    assert_eq!(client.is_closed(&pool_id_2), false);
-    assert_eq!(client.is_closed(&pool_id_3), true);
+    assert!(client.is_closed(&pool_id_1));
+    assert!(!client.is_closed(&pool_id_2));
+    assert!(client.is_closed(&pool_id_3));
 }
 
 #[test]
@@ -269,10 +331,10 @@ fn test_close_pool_state_transition_sequence() {
     let (client, admin, _) = setup_test(&env);
 
     let creator = Address::generate(&env);
-    let pool_id = create_test_pool(&client, &env, &creator);
+    let pool_id = create_multisig_test_pool(&client, &env, &creator);
 
     // Initial state: Active
-    assert_eq!(client.is_closed(&pool_id), false);
+    assert!(!client.is_closed(&pool_id));
 
     // Try to close from Active - should fail
     let result = client.try_close_pool(&pool_id, &admin);
@@ -282,12 +344,12 @@ fn test_close_pool_state_transition_sequence() {
     );
 
     // Transition to Disbursed
-    client.update_pool_state(&pool_id, &PoolState::Disbursed);
-    assert_eq!(client.is_closed(&pool_id), false);
+    disburse_test_pool(&client, &env, pool_id, &creator);
+    assert!(!client.is_closed(&pool_id));
 
     // Now close should succeed
     client.close_pool(&pool_id, &admin);
-    assert_eq!(client.is_closed(&pool_id), true);
+    assert!(client.is_closed(&pool_id));
 }
 
 #[test]
@@ -299,13 +361,13 @@ fn test_close_pool_after_refund_scenario() {
     let pool_id = create_test_pool(&client, &env, &creator);
 
     // Simulate refund scenario by setting state to Cancelled
-    client.update_pool_state(&pool_id, &PoolState::Cancelled);
+    client.update_pool_state(&pool_id, &PoolState::Cancelled, &admin);
 
     // Close the pool
     client.close_pool(&pool_id, &admin);
 
     // Verify pool is closed
-    assert_eq!(client.is_closed(&pool_id), true);
+    assert!(client.is_closed(&pool_id));
 }
 
 #[test]
@@ -320,22 +382,22 @@ fn test_is_closed_for_different_states() {
     let pool_paused = create_test_pool(&client, &env, &creator);
     let pool_completed = create_test_pool(&client, &env, &creator);
     let pool_cancelled = create_test_pool(&client, &env, &creator);
-    let pool_disbursed = create_test_pool(&client, &env, &creator);
-    let pool_closed = create_test_pool(&client, &env, &creator);
+    let pool_disbursed = create_multisig_test_pool(&client, &env, &creator);
+    let pool_closed = create_multisig_test_pool(&client, &env, &creator);
 
     // Set states
-    client.update_pool_state(&pool_paused, &PoolState::Paused);
-    client.update_pool_state(&pool_completed, &PoolState::Completed);
-    client.update_pool_state(&pool_cancelled, &PoolState::Cancelled);
-    client.update_pool_state(&pool_disbursed, &PoolState::Disbursed);
-    client.update_pool_state(&pool_closed, &PoolState::Disbursed);
+    client.update_pool_state(&pool_paused, &PoolState::Paused, &admin);
+    client.update_pool_state(&pool_completed, &PoolState::Completed, &admin);
+    client.update_pool_state(&pool_cancelled, &PoolState::Cancelled, &admin);
+    disburse_test_pool(&client, &env, pool_disbursed, &creator);
+    disburse_test_pool(&client, &env, pool_closed, &creator);
     client.close_pool(&pool_closed, &admin);
 
     // Verify is_closed returns false for all except Closed state
-    assert_eq!(client.is_closed(&pool_active), false);
-    assert_eq!(client.is_closed(&pool_paused), false);
-    assert_eq!(client.is_closed(&pool_completed), false);
-    assert_eq!(client.is_closed(&pool_cancelled), false);
-    assert_eq!(client.is_closed(&pool_disbursed), false);
-    assert_eq!(client.is_closed(&pool_closed), true);
+    assert!(!client.is_closed(&pool_active));
+    assert!(!client.is_closed(&pool_paused));
+    assert!(!client.is_closed(&pool_completed));
+    assert!(!client.is_closed(&pool_cancelled));
+    assert!(!client.is_closed(&pool_disbursed));
+    assert!(client.is_closed(&pool_closed));
 }
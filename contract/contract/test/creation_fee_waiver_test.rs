@@ -0,0 +1,143 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address, BytesN, Env, String, Vec};
+
+use crate::{
+    base::{errors::CrowdfundingError, types::PoolMetadata},
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+const CREATION_FEE: i128 = 1_000;
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&Address::generate(env), &token_address, &CREATION_FEE);
+
+    (client, token_address)
+}
+
+fn funded_creator(env: &Env, token_address: &Address) -> Address {
+    let creator = Address::generate(env);
+    token::StellarAssetClient::new(env, token_address).mint(&creator, &CREATION_FEE);
+    creator
+}
+
+fn verify(env: &Env, client: &CrowdfundingContractClient, cause: &Address) {
+    let verifier = Address::generate(env);
+    client.add_cause_verifier(&verifier);
+    client.verify_cause(
+        &verifier,
+        cause,
+        &None::<u64>,
+        &BytesN::from_array(env, &[7u8; 32]),
+        &String::from_str(env, "Health"),
+    );
+}
+
+fn create_campaign(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    creator: &Address,
+    token_address: &Address,
+) {
+    client.create_campaign(
+        &BytesN::from_array(env, &[1u8; 32]),
+        &String::from_str(env, "Verified Cause"),
+        creator,
+        &10_000,
+        &(env.ledger().timestamp() + 86400),
+        token_address,
+    );
+}
+
+#[test]
+fn test_unverified_creator_pays_full_fee() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    let creator = funded_creator(&env, &token_address);
+
+    create_campaign(&env, &client, &creator, &token_address);
+
+    assert_eq!(
+        token::Client::new(&env, &token_address).balance(&creator),
+        0
+    );
+    assert_eq!(client.get_platform_fees(&token_address), CREATION_FEE);
+}
+
+#[test]
+fn test_verified_cause_fee_waived_by_default() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    let creator = funded_creator(&env, &token_address);
+    verify(&env, &client, &creator);
+
+    assert_eq!(client.get_verified_cause_discount(), 10_000);
+    create_campaign(&env, &client, &creator, &token_address);
+
+    assert_eq!(
+        token::Client::new(&env, &token_address).balance(&creator),
+        CREATION_FEE
+    );
+    assert_eq!(client.get_platform_fees(&token_address), 0);
+}
+
+#[test]
+fn test_verified_cause_partial_discount() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    let creator = funded_creator(&env, &token_address);
+    verify(&env, &client, &creator);
+    client.set_verified_cause_discount(&2_500);
+
+    create_campaign(&env, &client, &creator, &token_address);
+
+    assert_eq!(
+        token::Client::new(&env, &token_address).balance(&creator),
+        250
+    );
+    assert_eq!(client.get_platform_fees(&token_address), 750);
+}
+
+#[test]
+fn test_pool_creation_is_not_charged() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    let metadata = PoolMetadata {
+        description: String::from_str(&env, "Fee pool"),
+        external_url: String::from_str(&env, ""),
+        image_hash: String::from_str(&env, ""),
+    };
+
+    // The creation fee only applies to campaigns
+    let unfunded = Address::generate(&env);
+    client.save_pool(
+        &String::from_str(&env, "Fee Pool"),
+        &metadata,
+        &unfunded,
+        &10_000i128,
+        &(env.ledger().timestamp() + 86400),
+        &None::<u32>,
+        &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
+    );
+    assert_eq!(client.get_platform_fees(&token_address), 0);
+}
+
+#[test]
+fn test_set_verified_cause_discount_rejects_over_100_percent() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    assert_eq!(
+        client.try_set_verified_cause_discount(&10_001),
+        Err(Ok(CrowdfundingError::InvalidFee))
+    );
+}
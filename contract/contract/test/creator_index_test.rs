@@ -0,0 +1,166 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, String};
+
+use crate::{
+    base::types::{PoolConfig, PoolMetadata},
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &token_address, &0);
+
+    (client, token_address)
+}
+
+fn create_campaign(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    token_address: &Address,
+    creator: &Address,
+    seed: u8,
+) -> BytesN<32> {
+    let campaign_id = BytesN::from_array(env, &[seed; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Creator Campaign"),
+        creator,
+        &1_000,
+        &(env.ledger().timestamp() + 86400),
+        token_address,
+    );
+    campaign_id
+}
+
+fn create_pool(env: &Env, client: &CrowdfundingContractClient, creator: &Address) -> u64 {
+    let config = PoolConfig {
+        name: String::from_str(env, "Creator Pool"),
+        description: String::from_str(env, "Pool used for creator index checks"),
+        target_amount: 1_000_000,
+        is_private: false,
+        duration: 86400,
+        created_at: env.ledger().timestamp(),
+    };
+    client.create_pool(creator, &config, &None, &false)
+}
+
+fn save_pool(env: &Env, client: &CrowdfundingContractClient, creator: &Address) -> u64 {
+    let metadata = PoolMetadata {
+        description: String::from_str(env, "Saved pool"),
+        external_url: String::from_str(env, ""),
+        image_hash: String::from_str(env, ""),
+    };
+    client.save_pool(
+        &String::from_str(env, "Saved Pool"),
+        &metadata,
+        creator,
+        &10_000i128,
+        &(env.ledger().timestamp() + 86400),
+        &None,
+        &None,
+        &None,
+        &false,
+    )
+}
+
+#[test]
+fn test_campaigns_are_indexed_by_creator() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let first = create_campaign(&env, &client, &token_address, &alice, 1);
+    let other = create_campaign(&env, &client, &token_address, &bob, 2);
+    let second = create_campaign(&env, &client, &token_address, &alice, 3);
+
+    assert_eq!(client.get_creator_campaign_count(&alice), 2);
+    assert_eq!(
+        client.get_creator_campaigns(&alice, &0, &10),
+        vec![&env, first, second.clone()]
+    );
+    assert_eq!(client.get_creator_campaign_count(&bob), 1);
+    assert_eq!(
+        client.get_creator_campaigns(&bob, &0, &10),
+        vec![&env, other]
+    );
+
+    assert_eq!(
+        client.get_creator_campaigns(&alice, &1, &10),
+        vec![&env, second]
+    );
+    assert_eq!(client.get_creator_campaigns(&alice, &2, &10).len(), 0);
+}
+
+#[test]
+fn test_pools_from_both_entry_points_are_indexed() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let creator = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let created = create_pool(&env, &client, &creator);
+    let foreign = create_pool(&env, &client, &other);
+    let saved = save_pool(&env, &client, &creator);
+
+    assert_eq!(client.get_creator_pool_count(&creator), 2);
+    assert_eq!(
+        client.get_creator_pools(&creator, &0, &10),
+        vec![&env, created, saved]
+    );
+    assert_eq!(
+        client.get_creator_pools(&other, &0, &10),
+        vec![&env, foreign]
+    );
+}
+
+#[test]
+fn test_creator_pools_paginate() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let creator = Address::generate(&env);
+    for _ in 0..3 {
+        create_pool(&env, &client, &creator);
+    }
+
+    assert_eq!(client.get_creator_pools(&creator, &0, &2), vec![&env, 1, 2]);
+    assert_eq!(client.get_creator_pools(&creator, &2, &2), vec![&env, 3]);
+    assert_eq!(
+        client.get_creator_pools(&creator, &0, &0),
+        vec![&env, 1, 2, 3]
+    );
+}
+
+#[test]
+fn test_campaigns_and_pools_are_counted_separately() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    let creator = Address::generate(&env);
+
+    create_campaign(&env, &client, &token_address, &creator, 1);
+    create_pool(&env, &client, &creator);
+    create_pool(&env, &client, &creator);
+
+    assert_eq!(client.get_creator_campaign_count(&creator), 1);
+    assert_eq!(client.get_creator_pool_count(&creator), 2);
+}
+
+#[test]
+fn test_unknown_creator_has_empty_index() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let nobody = Address::generate(&env);
+
+    assert_eq!(client.get_creator_campaign_count(&nobody), 0);
+    assert_eq!(client.get_creator_pool_count(&nobody), 0);
+    assert_eq!(client.get_creator_campaigns(&nobody, &0, &10).len(), 0);
+    assert_eq!(client.get_creator_pools(&nobody, &0, &10).len(), 0);
+}
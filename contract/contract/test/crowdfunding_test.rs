@@ -38,12 +38,13 @@ fn setup_test(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address) {
 #[test]
 fn test_create_campaign() {
     let env = Env::default();
-    let (client, _, token_address) = setup_test(&env);
+    let (client, _, _) = setup_test(&env);
 
     let admin = Address::generate(&env);
     let token_id = env
         .register_stellar_asset_contract_v2(admin.clone())
         .address();
+    client.add_supported_token(&token_id);
     let creator = Address::generate(&env);
     let campaign_id = create_test_campaign_id(&env, 1);
     let title = String::from_str(&env, "Save the Whales");
@@ -53,7 +54,7 @@ fn test_create_campaign() {
     client.create_campaign(&campaign_id, &title, &creator, &goal, &deadline, &token_id);
 
     let campaign = client.get_campaign(&campaign_id);
-    assert_eq!(campaign.token_address, token_address);
+    assert_eq!(campaign.token_address, token_id);
 }
 
 #[test]
@@ -65,6 +66,7 @@ fn test_get_campaign() {
     let token_id = env
         .register_stellar_asset_contract_v2(admin.clone())
         .address();
+    client.add_supported_token(&token_id);
     let creator = Address::generate(&env);
     let campaign_id = create_test_campaign_id(&env, 2);
     let title = String::from_str(&env, "Build a School");
@@ -190,6 +192,7 @@ fn test_create_duplicate_campaign() {
     let token_id = env
         .register_stellar_asset_contract_v2(admin.clone())
         .address();
+    client.add_supported_token(&token_id);
     let creator = Address::generate(&env);
     let campaign_id = create_test_campaign_id(&env, 7);
     let title = String::from_str(&env, "Duplicate Campaign");
@@ -303,6 +306,8 @@ fn test_save_pool() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     assert_eq!(pool_id, 1);
@@ -336,6 +341,8 @@ fn test_save_pool_validation() {
         &deadline,
         &None,
         &None,
+        &None::<Vec<Address>>,
+        &false,
     );
     assert_eq!(result, Err(Ok(CrowdfundingError::InvalidPoolName)));
 
@@ -349,6 +356,8 @@ fn test_save_pool_validation() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
     assert_eq!(result, Err(Ok(CrowdfundingError::InvalidPoolTarget)));
 
@@ -362,6 +371,8 @@ fn test_save_pool_validation() {
         &past_deadline,
         &None,
         &None,
+        &None::<Vec<Address>>,
+        &false,
     );
     assert_eq!(result, Err(Ok(CrowdfundingError::InvalidPoolDeadline)));
 }
@@ -392,6 +403,8 @@ fn test_get_pool() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     let pool = client.get_pool(&pool_id).unwrap();
@@ -461,13 +474,15 @@ fn test_update_pool_state() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     // Update state to Paused
-    client.update_pool_state(&pool_id, &PoolState::Paused);
+    client.update_pool_state(&pool_id, &PoolState::Paused, &creator);
 
     // Update state to Completed
-    client.update_pool_state(&pool_id, &PoolState::Completed);
+    client.update_pool_state(&pool_id, &PoolState::Completed, &creator);
 }
 
 #[test]
@@ -478,7 +493,7 @@ fn test_update_pool_state_nonexistent() {
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
 
-    let result = client.try_update_pool_state(&999, &PoolState::Paused);
+    let result = client.try_update_pool_state(&999, &PoolState::Paused, &Address::generate(&env));
     assert_eq!(result, Err(Ok(CrowdfundingError::PoolNotFound)));
 }
 
@@ -508,19 +523,101 @@ fn test_update_pool_state_invalid_transition() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     // First complete the pool
-    client.update_pool_state(&pool_id, &PoolState::Completed);
+    client.update_pool_state(&pool_id, &PoolState::Completed, &creator);
 
     // Try to change state from completed - should fail
-    let result = client.try_update_pool_state(&pool_id, &PoolState::Active);
+    let result = client.try_update_pool_state(&pool_id, &PoolState::Active, &creator);
     assert_eq!(result, Err(Ok(CrowdfundingError::InvalidPoolState)));
 
-    let result = client.try_update_pool_state(&pool_id, &PoolState::Paused);
+    let result = client.try_update_pool_state(&pool_id, &PoolState::Paused, &creator);
     assert_eq!(result, Err(Ok(CrowdfundingError::InvalidPoolState)));
 }
 
+#[test]
+fn test_update_pool_state_cannot_jump_to_disbursed() {
+    let env = Env::default();
+    let (client, admin, _) = setup_test(&env);
+
+    let creator = Address::generate(&env);
+    let metadata = PoolMetadata {
+        description: String::from_str(&env, "Undisbursed fund"),
+        external_url: String::from_str(&env, ""),
+        image_hash: String::from_str(&env, ""),
+    };
+    let pool_id = client.save_pool(
+        &String::from_str(&env, "Undisbursed Fund"),
+        &metadata,
+        &creator,
+        &10_000i128,
+        &(env.ledger().timestamp() + 86400),
+        &None::<u32>,
+        &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
+    );
+
+    let result = client.try_update_pool_state(&pool_id, &PoolState::Disbursed, &admin);
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+
+    let result = client.try_update_pool_state(&pool_id, &PoolState::Closed, &admin);
+    assert_eq!(
+        result,
+        Err(Ok(CrowdfundingError::PoolNotDisbursedOrRefunded))
+    );
+
+    client.update_pool_state(&pool_id, &PoolState::Cancelled, &creator);
+    let result = client.try_update_pool_state(&pool_id, &PoolState::Active, &creator);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidPoolState)));
+}
+
+#[test]
+fn test_update_pool_state_unauthorized() {
+    let env = Env::default();
+    let (client, admin, _) = setup_test(&env);
+
+    let creator = Address::generate(&env);
+    let metadata = PoolMetadata {
+        description: String::from_str(&env, "Managed fund"),
+        external_url: String::from_str(&env, ""),
+        image_hash: String::from_str(&env, ""),
+    };
+    let pool_id = client.save_pool(
+        &String::from_str(&env, "Managed Fund"),
+        &metadata,
+        &creator,
+        &10_000i128,
+        &(env.ledger().timestamp() + 86400),
+        &None::<u32>,
+        &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
+    );
+
+    assert_eq!(client.get_pool_creator(&pool_id), creator);
+
+    let stranger = Address::generate(&env);
+    let result = client.try_update_pool_state(&pool_id, &PoolState::Paused, &stranger);
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+
+    // Both the creator and the admin can manage the pool
+    client.update_pool_state(&pool_id, &PoolState::Paused, &creator);
+    client.update_pool_state(&pool_id, &PoolState::Active, &admin);
+}
+
+#[test]
+fn test_get_pool_creator_nonexistent() {
+    let env = Env::default();
+    let (client, _, _) = setup_test(&env);
+
+    let result = client.try_get_pool_creator(&999);
+    assert_eq!(result, Err(Ok(CrowdfundingError::PoolNotFound)));
+}
+
 #[test]
 fn test_multiple_pools() {
     let env = Env::default();
@@ -549,6 +646,8 @@ fn test_multiple_pools() {
         &deadline1,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     // Create second pool
@@ -568,6 +667,8 @@ fn test_multiple_pools() {
         &deadline2,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     assert_eq!(pool_id1, 1);
@@ -584,8 +685,8 @@ fn test_multiple_pools() {
     assert_eq!(pool2.target_amount, target2);
 
     // Update different states
-    client.update_pool_state(&pool_id1, &PoolState::Paused);
-    client.update_pool_state(&pool_id2, &PoolState::Active);
+    client.update_pool_state(&pool_id1, &PoolState::Paused, &creator1);
+    client.update_pool_state(&pool_id2, &PoolState::Cancelled, &creator2);
 }
 
 #[test]
@@ -715,6 +816,8 @@ fn test_operations_disabled_when_paused() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
     assert_eq!(result_pool, Err(Ok(CrowdfundingError::ContractPaused)));
 }
@@ -751,18 +854,20 @@ fn test_update_pool_state_blocked_when_paused() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     // Now pause the contract
     client.pause();
 
     // Try to update pool state - should fail
-    let result = client.try_update_pool_state(&pool_id, &PoolState::Paused);
+    let result = client.try_update_pool_state(&pool_id, &PoolState::Paused, &creator);
     assert_eq!(result, Err(Ok(CrowdfundingError::ContractPaused)));
 
     // Unpause and verify it works
     client.unpause();
-    client.update_pool_state(&pool_id, &PoolState::Paused);
+    client.update_pool_state(&pool_id, &PoolState::Paused, &creator);
 }
 
 #[test]
@@ -783,6 +888,7 @@ fn test_getters_work_when_paused() {
     let token_id = env
         .register_stellar_asset_contract_v2(admin.clone())
         .address();
+    client.add_supported_token(&token_id);
     let creator = Address::generate(&env);
     let camp_id = create_test_campaign_id(&env, 11);
     client.create_campaign(
@@ -857,6 +963,7 @@ fn test_operations_enabled_after_unpause() {
     let token_id = env
         .register_stellar_asset_contract_v2(admin.clone())
         .address();
+    client.add_supported_token(&token_id);
     let creator = Address::generate(&env);
     let camp_id = create_test_campaign_id(&env, 12);
     let title = String::from_str(&env, "After Unpause");
@@ -886,6 +993,7 @@ fn test_contribute_and_event_emission() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -906,6 +1014,8 @@ fn test_contribute_and_event_emission() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     // Advance ledger time
@@ -930,19 +1040,21 @@ fn test_contribute_and_event_emission() {
 // Getter & Donation Tests
 
 #[test]
-fn test_get_all_campaigns() {
+fn test_get_campaigns() {
     let env = Env::default();
     let (client, _, _) = setup_test(&env);
 
     // 1. Returns empty list when no campaigns exist
-    let campaigns = client.get_all_campaigns();
+    let campaigns = client.get_campaigns(&0, &10);
     assert_eq!(campaigns.len(), 0);
+    assert_eq!(client.get_campaign_count(), 0);
 
     // 2. Returns all campaign IDs after multiple campaigns created
     let admin = Address::generate(&env);
     let token_id = env
         .register_stellar_asset_contract_v2(admin.clone())
         .address();
+    client.add_supported_token(&token_id);
     let creator = Address::generate(&env);
     let id1 = create_test_campaign_id(&env, 101);
     let id2 = create_test_campaign_id(&env, 102);
@@ -964,10 +1076,11 @@ fn test_get_all_campaigns() {
         &token_id,
     );
 
-    let campaigns = client.get_all_campaigns();
+    let campaigns = client.get_campaigns(&0, &10);
     assert_eq!(campaigns.len(), 2);
     assert!(campaigns.contains(id1));
     assert!(campaigns.contains(id2));
+    assert_eq!(client.get_campaign_count(), 2);
 }
 
 #[test]
@@ -1025,6 +1138,7 @@ fn test_get_campaign_goal() {
     let token_id = env
         .register_stellar_asset_contract_v2(admin.clone())
         .address();
+    client.add_supported_token(&token_id);
     let creator = Address::generate(&env);
     let id = create_test_campaign_id(&env, 104);
     let goal = 5555i128;
@@ -1655,7 +1769,7 @@ fn test_donate_campaign_already_funded() {
 
     // Verify campaign is completed
     assert_eq!(client.get_total_raised(&campaign_id), goal);
-    assert_eq!(client.is_campaign_completed(&campaign_id), true);
+    assert!(client.is_campaign_completed(&campaign_id));
 
     // Try to donate again - should fail
     let result = client.try_donate(&campaign_id, &donor, &token_id, &100i128);
@@ -1798,6 +1912,7 @@ fn test_refund_after_deadline_and_grace_period() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     // Create pool with deadline
     let creator = Address::generate(&env);
@@ -1821,6 +1936,8 @@ fn test_refund_after_deadline_and_grace_period() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     // Mint tokens to contributor
@@ -1866,6 +1983,7 @@ fn test_refund_partial_contribution() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     // Create pool
     let creator = Address::generate(&env);
@@ -1889,6 +2007,8 @@ fn test_refund_partial_contribution() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     // Mint tokens
@@ -1945,6 +2065,7 @@ fn test_refund_fails_before_deadline() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -1967,6 +2088,8 @@ fn test_refund_fails_before_deadline() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     token_admin_client.mint(&contributor, &5_000i128);
@@ -1994,6 +2117,7 @@ fn test_refund_fails_before_grace_period() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -2016,6 +2140,8 @@ fn test_refund_fails_before_grace_period() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     token_admin_client.mint(&contributor, &5_000i128);
@@ -2051,6 +2177,7 @@ fn test_refund_fails_if_disbursed() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -2071,8 +2198,10 @@ fn test_refund_fails_if_disbursed() {
         &creator,
         &target_amount,
         &deadline,
-        &None::<u32>,
+        &Some(1u32),
+        &Some(Vec::from_array(&env, [creator.clone()])),
         &None::<Vec<Address>>,
+        &false,
     );
 
     token_admin_client.mint(&contributor, &5_000i128);
@@ -2084,8 +2213,15 @@ fn test_refund_fails_if_disbursed() {
         &false,
     );
 
-    // Mark pool as disbursed
-    client.update_pool_state(&pool_id, &PoolState::Disbursed);
+    // Disburse the pool through its multisig
+    let disbursement_id = client.propose_disbursement(
+        &pool_id,
+        &creator,
+        &Address::generate(&env),
+        &token_id.address(),
+        &1_000i128,
+    );
+    client.execute_disbursement(&pool_id, &disbursement_id, &creator);
 
     // Advance time past deadline + grace period
     let grace_period = 604800u64;
@@ -2155,6 +2291,8 @@ fn test_refund_fails_no_contribution() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     // Advance time past deadline + grace period
@@ -2179,6 +2317,7 @@ fn test_multiple_contributors_refund_independently() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor1 = Address::generate(&env);
@@ -2202,6 +2341,8 @@ fn test_multiple_contributors_refund_independently() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     // Both contributors contribute
@@ -2252,6 +2393,7 @@ fn test_refund_fails_after_already_refunded() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -2274,6 +2416,8 @@ fn test_refund_fails_after_already_refunded() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     token_admin_client.mint(&contributor, &5_000i128);
@@ -2443,6 +2587,7 @@ fn test_contribute_to_pool_success() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -2463,6 +2608,8 @@ fn test_contribute_to_pool_success() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     token_admin_client.mint(&contributor, &5_000i128);
@@ -2515,6 +2662,8 @@ fn test_contribute_to_paused_pool() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     client.pause();
@@ -2556,9 +2705,11 @@ fn test_contribute_to_non_active_pool() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
-    client.update_pool_state(&pool_id, &PoolState::Completed);
+    client.update_pool_state(&pool_id, &PoolState::Completed, &creator);
 
     token_admin_client.mint(&contributor, &5_000i128);
 
@@ -2602,6 +2753,8 @@ fn test_contribute_zero_amount() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     let result = client.try_contribute(&pool_id, &contributor, &token_id.address(), &0i128, &false);
@@ -2642,6 +2795,7 @@ fn test_contribute_multiple_contributors() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor1 = Address::generate(&env);
@@ -2665,6 +2819,8 @@ fn test_contribute_multiple_contributors() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     token_admin_client.mint(&contributor1, &5_000i128);
@@ -2705,6 +2861,7 @@ fn test_contribute_private_contribution() {
 
     let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token_id.address(), &0);
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
@@ -2725,6 +2882,8 @@ fn test_contribute_private_contribution() {
         &deadline,
         &None::<u32>,
         &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
     );
 
     token_admin_client.mint(&contributor, &5_000i128);
@@ -2875,6 +3034,7 @@ fn test_get_active_campaign_count_all_expired() {
     assert_eq!(client.get_active_campaign_count(), 0);
 }
 
+#[test]
 fn test_withdraw_platform_fees_success() {
     let env = Env::default();
     let (client, admin, token_address) = setup_test(&env);
@@ -2902,7 +3062,7 @@ fn test_withdraw_platform_fees_success() {
     let token_client = token::Client::new(&env, &token_address);
     let admin_balance_before = token_client.balance(&admin);
 
-    client.withdraw_platform_fees(&admin, &100);
+    client.withdraw_platform_fees(&admin, &token_address, &admin, &100);
 
     let admin_balance_after = token_client.balance(&admin);
     assert_eq!(admin_balance_after - admin_balance_before, 100);
@@ -3020,22 +3180,22 @@ fn test_get_active_campaign_count_unaffected_by_donation_status() {
     // fully funded but deadline not passed - still counts as active
     assert_eq!(client.get_active_campaign_count(), 1);
     let non_admin = Address::generate(&env);
-    let res = client.try_withdraw_platform_fees(&non_admin, &100);
+    let res = client.try_withdraw_platform_fees(&non_admin, &token_address, &non_admin, &100);
     assert_eq!(res, Err(Ok(CrowdfundingError::Unauthorized)));
 }
 
 #[test]
 fn test_withdraw_platform_fees_insufficient_fees() {
     let env = Env::default();
-    let (client, admin, _) = setup_test(&env);
+    let (client, admin, token_address) = setup_test(&env);
 
-    let res = client.try_withdraw_platform_fees(&admin, &100);
+    let res = client.try_withdraw_platform_fees(&admin, &token_address, &admin, &100);
     assert_eq!(res, Err(Ok(CrowdfundingError::InsufficientFees)));
 }
 #[test]
 fn test_set_emergency_contact_success() {
     let env = Env::default();
-    let (client, admin, _) = setup_test(&env);
+    let (client, _admin, _) = setup_test(&env);
 
     let emergency_contact = Address::generate(&env);
 
@@ -3049,7 +3209,7 @@ fn test_set_emergency_contact_success() {
 #[test]
 fn test_set_emergency_contact_updates_existing() {
     let env = Env::default();
-    let (client, admin, _) = setup_test(&env);
+    let (client, _admin, _) = setup_test(&env);
 
     let contact1 = Address::generate(&env);
     client.set_emergency_contact(&contact1);
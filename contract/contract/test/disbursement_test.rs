@@ -0,0 +1,464 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String, Vec,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        types::{PoolMetadata, PoolState},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+struct TestContext<'a> {
+    client: CrowdfundingContractClient<'a>,
+    admin: Address,
+    token_address: Address,
+    signers: Vec<Address>,
+    contributor: Address,
+    pool_id: u64,
+}
+
+fn setup_multisig_pool(env: &Env, required_signatures: u32, contribution: i128) -> TestContext<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
+    let token_address = token_contract.address();
+
+    client.initialize(&admin, &token_address, &0);
+
+    let signers = vec![
+        env,
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+
+    let creator = Address::generate(env);
+    let metadata = PoolMetadata {
+        description: String::from_str(env, "Multisig managed pool"),
+        external_url: String::from_str(env, ""),
+        image_hash: String::from_str(env, ""),
+    };
+    let pool_id = client.save_pool(
+        &String::from_str(env, "Treasury Pool"),
+        &metadata,
+        &creator,
+        &10_000i128,
+        &(env.ledger().timestamp() + 86400),
+        &Some(required_signatures),
+        &Some(signers.clone()),
+        &None::<Vec<Address>>,
+        &false,
+    );
+
+    let contributor = Address::generate(env);
+    if contribution > 0 {
+        let token_admin_client = token::StellarAssetClient::new(env, &token_address);
+        token_admin_client.mint(&contributor, &contribution);
+        client.contribute(
+            &pool_id,
+            &contributor,
+            &token_address,
+            &contribution,
+            &false,
+        );
+    }
+
+    TestContext {
+        client,
+        admin,
+        token_address,
+        signers,
+        contributor,
+        pool_id,
+    }
+}
+
+#[test]
+fn test_disbursement_full_flow() {
+    let env = Env::default();
+    let ctx = setup_multisig_pool(&env, 2, 5_000);
+    let recipient = Address::generate(&env);
+    let proposer = ctx.signers.get(0).unwrap();
+    let approver = ctx.signers.get(1).unwrap();
+
+    let disbursement_id = ctx.client.propose_disbursement(
+        &ctx.pool_id,
+        &proposer,
+        &recipient,
+        &ctx.token_address,
+        &4_000,
+    );
+    assert_eq!(disbursement_id, 1);
+    assert_eq!(ctx.client.get_disbursement_count(&ctx.pool_id), 1);
+
+    let request = ctx.client.get_disbursement(&ctx.pool_id, &disbursement_id);
+    assert_eq!(request.approvals, vec![&env, proposer.clone()]);
+    assert!(!request.executed);
+
+    ctx.client
+        .approve_disbursement(&ctx.pool_id, &disbursement_id, &approver);
+    ctx.client
+        .execute_disbursement(&ctx.pool_id, &disbursement_id, &approver);
+
+    let token_client = token::Client::new(&env, &ctx.token_address);
+    assert_eq!(token_client.balance(&recipient), 4_000);
+
+    let request = ctx.client.get_disbursement(&ctx.pool_id, &disbursement_id);
+    assert!(request.executed);
+    assert_eq!(request.approvals.len(), 2);
+}
+
+#[test]
+fn test_disbursement_moves_pool_to_disbursed() {
+    let env = Env::default();
+    let ctx = setup_multisig_pool(&env, 1, 5_000);
+    let recipient = Address::generate(&env);
+    let signer = ctx.signers.get(0).unwrap();
+
+    let disbursement_id = ctx.client.propose_disbursement(
+        &ctx.pool_id,
+        &signer,
+        &recipient,
+        &ctx.token_address,
+        &5_000,
+    );
+    ctx.client
+        .execute_disbursement(&ctx.pool_id, &disbursement_id, &signer);
+
+    let result = ctx.client.try_propose_disbursement(
+        &ctx.pool_id,
+        &signer,
+        &recipient,
+        &ctx.token_address,
+        &1,
+    );
+    assert_eq!(result, Err(Ok(CrowdfundingError::PoolAlreadyDisbursed)));
+
+    // Only disbursed or cancelled pools can be closed
+    ctx.client.close_pool(&ctx.pool_id, &ctx.admin);
+    assert!(ctx.client.is_closed(&ctx.pool_id));
+}
+
+#[test]
+fn test_partial_disbursement_keeps_remainder_payable() {
+    let env = Env::default();
+    let ctx = setup_multisig_pool(&env, 1, 5_000);
+    let recipient = Address::generate(&env);
+    let signer = ctx.signers.get(0).unwrap();
+
+    let first = ctx.client.propose_disbursement(
+        &ctx.pool_id,
+        &signer,
+        &recipient,
+        &ctx.token_address,
+        &4_000,
+    );
+    ctx.client
+        .execute_disbursement(&ctx.pool_id, &first, &signer);
+    assert_eq!(ctx.client.get_pool_state(&ctx.pool_id), PoolState::Active);
+
+    // The remainder cannot be overdrawn
+    let result = ctx.client.try_propose_disbursement(
+        &ctx.pool_id,
+        &signer,
+        &recipient,
+        &ctx.token_address,
+        &1_001,
+    );
+    assert_eq!(result, Err(Ok(CrowdfundingError::InsufficientBalance)));
+
+    let second = ctx.client.propose_disbursement(
+        &ctx.pool_id,
+        &signer,
+        &recipient,
+        &ctx.token_address,
+        &1_000,
+    );
+    ctx.client
+        .execute_disbursement(&ctx.pool_id, &second, &signer);
+
+    let token_client = token::Client::new(&env, &ctx.token_address);
+    assert_eq!(token_client.balance(&recipient), 5_000);
+    assert_eq!(
+        ctx.client.get_pool_state(&ctx.pool_id),
+        PoolState::Disbursed
+    );
+}
+
+#[test]
+fn test_refunds_blocked_after_partial_disbursement() {
+    let env = Env::default();
+    let ctx = setup_multisig_pool(&env, 1, 5_000);
+    let recipient = Address::generate(&env);
+    let signer = ctx.signers.get(0).unwrap();
+
+    let other_contributor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &ctx.token_address).mint(&other_contributor, &5_000);
+    ctx.client.contribute(
+        &ctx.pool_id,
+        &other_contributor,
+        &ctx.token_address,
+        &5_000,
+        &false,
+    );
+
+    let first = ctx.client.propose_disbursement(
+        &ctx.pool_id,
+        &signer,
+        &recipient,
+        &ctx.token_address,
+        &5_000,
+    );
+    ctx.client
+        .execute_disbursement(&ctx.pool_id, &first, &signer);
+
+    // Past the deadline and the refund grace period, neither contributor can
+    // claim the remainder ahead of the other
+    env.ledger()
+        .with_mut(|li| li.timestamp += 86400 + 604800 + 1);
+    for contributor in [ctx.contributor.clone(), other_contributor.clone()] {
+        let result = ctx.client.try_refund(&ctx.pool_id, &contributor);
+        assert_eq!(result, Err(Ok(CrowdfundingError::PoolAlreadyDisbursed)));
+    }
+
+    let token_client = token::Client::new(&env, &ctx.token_address);
+    assert_eq!(token_client.balance(&ctx.contributor), 0);
+    assert_eq!(token_client.balance(&other_contributor), 0);
+
+    // The remainder is still paid out through the multisig
+    let second = ctx.client.propose_disbursement(
+        &ctx.pool_id,
+        &signer,
+        &recipient,
+        &ctx.token_address,
+        &5_000,
+    );
+    ctx.client
+        .execute_disbursement(&ctx.pool_id, &second, &signer);
+    assert_eq!(token_client.balance(&recipient), 10_000);
+    assert_eq!(
+        ctx.client.get_pool_state(&ctx.pool_id),
+        PoolState::Disbursed
+    );
+}
+
+#[test]
+fn test_propose_disbursement_non_signer() {
+    let env = Env::default();
+    let ctx = setup_multisig_pool(&env, 2, 5_000);
+    let outsider = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    let result = ctx.client.try_propose_disbursement(
+        &ctx.pool_id,
+        &outsider,
+        &recipient,
+        &ctx.token_address,
+        &1_000,
+    );
+    assert_eq!(result, Err(Ok(CrowdfundingError::NotAuthorizedSigner)));
+}
+
+#[test]
+fn test_propose_disbursement_invalid_amount() {
+    let env = Env::default();
+    let ctx = setup_multisig_pool(&env, 2, 5_000);
+    let recipient = Address::generate(&env);
+    let signer = ctx.signers.get(0).unwrap();
+
+    let result = ctx.client.try_propose_disbursement(
+        &ctx.pool_id,
+        &signer,
+        &recipient,
+        &ctx.token_address,
+        &0,
+    );
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidAmount)));
+}
+
+#[test]
+fn test_propose_disbursement_exceeds_raised() {
+    let env = Env::default();
+    let ctx = setup_multisig_pool(&env, 2, 5_000);
+    let recipient = Address::generate(&env);
+    let signer = ctx.signers.get(0).unwrap();
+
+    let result = ctx.client.try_propose_disbursement(
+        &ctx.pool_id,
+        &signer,
+        &recipient,
+        &ctx.token_address,
+        &5_001,
+    );
+    assert_eq!(result, Err(Ok(CrowdfundingError::InsufficientBalance)));
+}
+
+#[test]
+fn test_propose_disbursement_without_multisig() {
+    let env = Env::default();
+    let ctx = setup_multisig_pool(&env, 2, 0);
+    let creator = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    let metadata = PoolMetadata {
+        description: String::from_str(&env, "No multisig"),
+        external_url: String::from_str(&env, ""),
+        image_hash: String::from_str(&env, ""),
+    };
+    let pool_id = ctx.client.save_pool(
+        &String::from_str(&env, "Plain Pool"),
+        &metadata,
+        &creator,
+        &10_000i128,
+        &(env.ledger().timestamp() + 86400),
+        &None::<u32>,
+        &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
+    );
+
+    let result = ctx.client.try_propose_disbursement(
+        &pool_id,
+        &creator,
+        &recipient,
+        &ctx.token_address,
+        &100,
+    );
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidMultiSigConfig)));
+}
+
+#[test]
+fn test_approve_disbursement_twice() {
+    let env = Env::default();
+    let ctx = setup_multisig_pool(&env, 2, 5_000);
+    let recipient = Address::generate(&env);
+    let proposer = ctx.signers.get(0).unwrap();
+
+    let disbursement_id = ctx.client.propose_disbursement(
+        &ctx.pool_id,
+        &proposer,
+        &recipient,
+        &ctx.token_address,
+        &1_000,
+    );
+
+    let result = ctx
+        .client
+        .try_approve_disbursement(&ctx.pool_id, &disbursement_id, &proposer);
+    assert_eq!(result, Err(Ok(CrowdfundingError::AlreadyApproved)));
+}
+
+#[test]
+fn test_approve_disbursement_not_found() {
+    let env = Env::default();
+    let ctx = setup_multisig_pool(&env, 2, 5_000);
+    let signer = ctx.signers.get(0).unwrap();
+
+    let result = ctx
+        .client
+        .try_approve_disbursement(&ctx.pool_id, &42, &signer);
+    assert_eq!(result, Err(Ok(CrowdfundingError::DisbursementNotFound)));
+}
+
+#[test]
+fn test_execute_disbursement_insufficient_approvals() {
+    let env = Env::default();
+    let ctx = setup_multisig_pool(&env, 3, 5_000);
+    let recipient = Address::generate(&env);
+    let proposer = ctx.signers.get(0).unwrap();
+    let approver = ctx.signers.get(1).unwrap();
+
+    let disbursement_id = ctx.client.propose_disbursement(
+        &ctx.pool_id,
+        &proposer,
+        &recipient,
+        &ctx.token_address,
+        &1_000,
+    );
+    ctx.client
+        .approve_disbursement(&ctx.pool_id, &disbursement_id, &approver);
+
+    let result = ctx
+        .client
+        .try_execute_disbursement(&ctx.pool_id, &disbursement_id, &proposer);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InsufficientApprovals)));
+}
+
+#[test]
+fn test_execute_disbursement_twice() {
+    let env = Env::default();
+    let ctx = setup_multisig_pool(&env, 1, 5_000);
+    let recipient = Address::generate(&env);
+    let signer = ctx.signers.get(0).unwrap();
+
+    let disbursement_id = ctx.client.propose_disbursement(
+        &ctx.pool_id,
+        &signer,
+        &recipient,
+        &ctx.token_address,
+        &1_000,
+    );
+    ctx.client
+        .execute_disbursement(&ctx.pool_id, &disbursement_id, &signer);
+
+    let result = ctx
+        .client
+        .try_execute_disbursement(&ctx.pool_id, &disbursement_id, &signer);
+    assert_eq!(
+        result,
+        Err(Ok(CrowdfundingError::DisbursementAlreadyExecuted))
+    );
+}
+
+#[test]
+fn test_execute_second_disbursement_after_pool_disbursed() {
+    let env = Env::default();
+    let ctx = setup_multisig_pool(&env, 1, 5_000);
+    let recipient = Address::generate(&env);
+    let signer = ctx.signers.get(0).unwrap();
+
+    let first = ctx.client.propose_disbursement(
+        &ctx.pool_id,
+        &signer,
+        &recipient,
+        &ctx.token_address,
+        &5_000,
+    );
+    let second = ctx.client.propose_disbursement(
+        &ctx.pool_id,
+        &signer,
+        &recipient,
+        &ctx.token_address,
+        &1_000,
+    );
+    // The first payout drains the pool, which moves it to Disbursed
+    ctx.client
+        .execute_disbursement(&ctx.pool_id, &first, &signer);
+
+    let result = ctx
+        .client
+        .try_execute_disbursement(&ctx.pool_id, &second, &signer);
+    assert_eq!(result, Err(Ok(CrowdfundingError::PoolAlreadyDisbursed)));
+}
+
+#[test]
+fn test_get_multisig_config() {
+    let env = Env::default();
+    let ctx = setup_multisig_pool(&env, 2, 0);
+
+    let config = ctx.client.get_multisig_config(&ctx.pool_id);
+    assert_eq!(config.required_signatures, 2);
+    assert_eq!(config.signers, ctx.signers);
+
+    let result = ctx.client.try_get_multisig_config(&999);
+    assert_eq!(result, Err(Ok(CrowdfundingError::PoolNotFound)));
+}
@@ -0,0 +1,158 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, BytesN, Env, String, Vec};
+
+use crate::{
+    base::{errors::CrowdfundingError, types::FeeRecipient},
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&Address::generate(env), &token_address, &0);
+    client.set_platform_fee(&1_000);
+
+    (client, token_address)
+}
+
+fn collect_fees(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    token_address: &Address,
+    seed: u8,
+    donation: i128,
+) {
+    let campaign_id = BytesN::from_array(env, &[seed; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Split Campaign"),
+        &Address::generate(env),
+        &1_000_000,
+        &(env.ledger().timestamp() + 86400),
+        token_address,
+    );
+    let donor = Address::generate(env);
+    token::StellarAssetClient::new(env, token_address).mint(&donor, &donation);
+    client.donate(&campaign_id, &donor, token_address, &donation);
+}
+
+fn share(recipient: &Address, share_bps: u32) -> FeeRecipient {
+    FeeRecipient {
+        recipient: recipient.clone(),
+        share_bps,
+    }
+}
+
+#[test]
+fn test_distribute_platform_fees_by_share() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    let ngo = Address::generate(&env);
+    let dev_fund = Address::generate(&env);
+
+    let split = vec![&env, share(&ngo, 7_000), share(&dev_fund, 3_000)];
+    client.set_fee_split(&split);
+    assert_eq!(client.get_fee_split(), split);
+
+    collect_fees(&env, &client, &token_address, 1, 10_000);
+    assert_eq!(client.get_platform_fees(&token_address), 1_000);
+
+    client.distribute_platform_fees(&Address::generate(&env));
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&ngo), 700);
+    assert_eq!(token_client.balance(&dev_fund), 300);
+    assert_eq!(client.get_platform_fees(&token_address), 0);
+}
+
+#[test]
+fn test_distribute_covers_every_fee_token() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    let usdc = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_supported_token(&usdc);
+
+    let treasury = Address::generate(&env);
+    client.set_fee_split(&vec![&env, share(&treasury, 10_000)]);
+
+    collect_fees(&env, &client, &token_address, 1, 1_000);
+    collect_fees(&env, &client, &usdc, 2, 2_000);
+    client.distribute_platform_fees(&treasury);
+
+    assert_eq!(
+        token::Client::new(&env, &token_address).balance(&treasury),
+        100
+    );
+    assert_eq!(token::Client::new(&env, &usdc).balance(&treasury), 200);
+}
+
+#[test]
+fn test_rounding_dust_stays_in_ledger() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    client.set_fee_split(&vec![
+        &env,
+        share(&a, 3_334),
+        share(&b, 3_333),
+        share(&c, 3_333),
+    ]);
+
+    collect_fees(&env, &client, &token_address, 1, 100);
+    client.distribute_platform_fees(&a);
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&a), 3);
+    assert_eq!(token_client.balance(&b), 3);
+    assert_eq!(token_client.balance(&c), 3);
+    assert_eq!(client.get_platform_fees(&token_address), 1);
+}
+
+#[test]
+fn test_distribute_requires_split_and_fees() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let caller = Address::generate(&env);
+
+    assert_eq!(
+        client.try_distribute_platform_fees(&caller),
+        Err(Ok(CrowdfundingError::InvalidFee))
+    );
+
+    client.set_fee_split(&vec![&env, share(&caller, 10_000)]);
+    assert_eq!(
+        client.try_distribute_platform_fees(&caller),
+        Err(Ok(CrowdfundingError::NothingToWithdraw))
+    );
+}
+
+#[test]
+fn test_set_fee_split_rejects_invalid_tables() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+
+    let invalid: [Vec<FeeRecipient>; 4] = [
+        Vec::new(&env),
+        vec![&env, share(&a, 6_000), share(&b, 3_000)],
+        vec![&env, share(&a, 5_000), share(&a, 5_000)],
+        vec![&env, share(&a, 10_000), share(&b, 0)],
+    ];
+    for split in invalid.iter() {
+        assert_eq!(
+            client.try_set_fee_split(split),
+            Err(Ok(CrowdfundingError::InvalidFee))
+        );
+    }
+}
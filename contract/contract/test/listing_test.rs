@@ -0,0 +1,234 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, String,
+};
+
+use crate::{
+    base::types::{CampaignLifecycleStatus, PoolConfig, PoolState},
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &token_address, &0);
+
+    (client, admin, token_address)
+}
+
+fn create_campaign(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    token_address: &Address,
+    seed: u8,
+    deadline: u64,
+) -> BytesN<32> {
+    let campaign_id = BytesN::from_array(env, &[seed; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Listed Campaign"),
+        &Address::generate(env),
+        &1_000,
+        &deadline,
+        token_address,
+    );
+    campaign_id
+}
+
+fn create_pool(env: &Env, client: &CrowdfundingContractClient, creator: &Address) -> u64 {
+    let config = PoolConfig {
+        name: String::from_str(env, "Listed Pool"),
+        description: String::from_str(env, "Pool used for listing checks"),
+        target_amount: 1_000_000,
+        is_private: false,
+        duration: 86400,
+        created_at: env.ledger().timestamp(),
+    };
+    client.create_pool(creator, &config, &None, &false)
+}
+
+#[test]
+fn test_list_campaigns_returns_summaries() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+    let campaign_id = create_campaign(&env, &client, &token_address, 1, 5000);
+
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_address).mint(&donor, &400);
+    client.donate(&campaign_id, &donor, &token_address, &400);
+
+    let page = client.list_campaigns(&0, &10, &None);
+    assert_eq!(page.next_cursor, None);
+    assert_eq!(page.items.len(), 1);
+
+    let summary = page.items.get(0).unwrap();
+    assert_eq!(summary.campaign, client.get_campaign(&campaign_id));
+    assert_eq!(summary.status, CampaignLifecycleStatus::Live);
+    assert_eq!(summary.metrics.total_raised, 400);
+    assert_eq!(summary.metrics.contributor_count, 1);
+}
+
+#[test]
+fn test_list_campaigns_pages_with_cursor() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+    for seed in 1u8..=5 {
+        create_campaign(&env, &client, &token_address, seed, 5000);
+    }
+
+    let first = client.list_campaigns(&0, &2, &None);
+    assert_eq!(first.items.len(), 2);
+    assert_eq!(first.next_cursor, Some(2));
+
+    let second = client.list_campaigns(&first.next_cursor.unwrap(), &2, &None);
+    assert_eq!(second.items.len(), 2);
+    assert_eq!(second.next_cursor, Some(4));
+
+    let last = client.list_campaigns(&second.next_cursor.unwrap(), &2, &None);
+    assert_eq!(last.items.len(), 1);
+    assert_eq!(last.next_cursor, None);
+    assert_eq!(
+        last.items.get(0).unwrap().campaign.id,
+        BytesN::from_array(&env, &[5u8; 32])
+    );
+}
+
+#[test]
+fn test_list_campaigns_filters_by_status() {
+    let env = Env::default();
+    let (client, admin, token_address) = setup(&env);
+    let live_id = create_campaign(&env, &client, &token_address, 1, 5000);
+    let expired_id = create_campaign(&env, &client, &token_address, 2, 2000);
+    let cancelled_id = create_campaign(&env, &client, &token_address, 3, 5000);
+    client.cancel_campaign(
+        &cancelled_id,
+        &admin,
+        &String::from_str(&env, "Duplicate listing"),
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 3000);
+
+    let live = client.list_campaigns(&0, &10, &Some(CampaignLifecycleStatus::Live));
+    assert_eq!(live.items.len(), 1);
+    assert_eq!(live.items.get(0).unwrap().campaign.id, live_id);
+
+    let expired = client.list_campaigns(&0, &10, &Some(CampaignLifecycleStatus::Expired));
+    assert_eq!(expired.items.len(), 1);
+    assert_eq!(expired.items.get(0).unwrap().campaign.id, expired_id);
+
+    let cancelled = client.list_campaigns(&0, &10, &Some(CampaignLifecycleStatus::Cancelled));
+    assert_eq!(cancelled.items.len(), 1);
+    assert_eq!(cancelled.items.get(0).unwrap().campaign.id, cancelled_id);
+
+    let successful = client.list_campaigns(&0, &10, &Some(CampaignLifecycleStatus::Successful));
+    assert_eq!(successful.items.len(), 0);
+    assert_eq!(successful.next_cursor, None);
+}
+
+#[test]
+fn test_list_campaigns_empty() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    let page = client.list_campaigns(&0, &10, &None);
+    assert_eq!(page.items.len(), 0);
+    assert_eq!(page.next_cursor, None);
+}
+
+#[test]
+fn test_list_pools_returns_summaries() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+    let creator = Address::generate(&env);
+    let pool_id = create_pool(&env, &client, &creator);
+
+    let contributor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_address).mint(&contributor, &250);
+    client.contribute(&pool_id, &contributor, &token_address, &250, &false);
+
+    let page = client.list_pools(&0, &10, &None);
+    assert_eq!(page.next_cursor, None);
+    assert_eq!(page.items.len(), 1);
+
+    let summary = page.items.get(0).unwrap();
+    assert_eq!(summary.pool_id, pool_id);
+    assert_eq!(Some(summary.config), client.get_pool(&pool_id));
+    assert_eq!(summary.state, PoolState::Active);
+    assert_eq!(summary.metrics, client.get_pool_metrics(&pool_id));
+}
+
+#[test]
+fn test_list_pools_pages_with_cursor() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+    let creator = Address::generate(&env);
+    for _ in 0..3 {
+        create_pool(&env, &client, &creator);
+    }
+
+    let first = client.list_pools(&0, &2, &None);
+    assert_eq!(first.items.len(), 2);
+    assert_eq!(first.items.get(0).unwrap().pool_id, 1);
+    assert_eq!(first.next_cursor, Some(3));
+
+    let last = client.list_pools(&first.next_cursor.unwrap(), &2, &None);
+    assert_eq!(last.items.len(), 1);
+    assert_eq!(last.items.get(0).unwrap().pool_id, 3);
+    assert_eq!(last.next_cursor, None);
+}
+
+#[test]
+fn test_zero_limit_returns_a_full_page() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+    let creator = Address::generate(&env);
+    for seed in 1u8..=3 {
+        create_campaign(&env, &client, &token_address, seed, 5000);
+        create_pool(&env, &client, &creator);
+    }
+
+    let campaigns = client.list_campaigns(&1, &0, &None);
+    assert_eq!(campaigns.items.len(), 2);
+    assert_eq!(campaigns.next_cursor, None);
+
+    let pools = client.list_pools(&2, &0, &None);
+    assert_eq!(pools.items.len(), 2);
+    assert_eq!(pools.next_cursor, None);
+}
+
+#[test]
+fn test_list_pools_filters_by_state() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+    let creator = Address::generate(&env);
+    let active_id = create_pool(&env, &client, &creator);
+    let paused_id = create_pool(&env, &client, &creator);
+    client.update_pool_state(&paused_id, &PoolState::Paused, &creator);
+
+    let active = client.list_pools(&0, &10, &Some(PoolState::Active));
+    assert_eq!(active.items.len(), 1);
+    assert_eq!(active.items.get(0).unwrap().pool_id, active_id);
+
+    let paused = client.list_pools(&0, &10, &Some(PoolState::Paused));
+    assert_eq!(paused.items.len(), 1);
+    assert_eq!(paused.items.get(0).unwrap().pool_id, paused_id);
+}
+
+#[test]
+fn test_list_pools_empty() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    let page = client.list_pools(&0, &10, &None);
+    assert_eq!(page.items.len(), 0);
+    assert_eq!(page.next_cursor, None);
+}
@@ -1,4 +1,24 @@
+mod campaign_refund_test;
+mod campaign_registry_test;
+mod campaign_token_test;
+mod campaign_withdrawal_test;
+mod cancel_campaign_test;
 mod close_pool_test;
 mod create_pool;
+mod creation_fee_waiver_test;
+mod creator_index_test;
 mod crowdfunding_test;
+mod disbursement_test;
+mod fee_split_test;
+mod listing_test;
+mod multi_asset_pool_test;
+mod platform_fee_test;
+mod pool_accepted_assets_test;
+mod pool_contribution_limits_test;
+mod pool_state_machine_test;
+mod signer_management_test;
+mod storage_layout_test;
+mod token_registry_test;
+mod ttl_test;
+mod verified_recipient_pool_test;
 mod verify_cause;
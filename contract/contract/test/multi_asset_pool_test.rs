@@ -0,0 +1,247 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String, Symbol,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        types::{PoolCapMode, PoolContribution, PoolMetadata, PoolState, StorageKey},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+const DURATION: u64 = 86400;
+const REFUND_GRACE_PERIOD: u64 = 604800;
+
+fn setup_pool(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address, u64) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let usdc = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let xlm = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &usdc, &0);
+    client.add_supported_token(&xlm);
+
+    let metadata = PoolMetadata {
+        description: String::from_str(env, "Multi-asset pool"),
+        external_url: String::from_str(env, ""),
+        image_hash: String::from_str(env, ""),
+    };
+    let creator = Address::generate(env);
+    let pool_id = client.save_pool(
+        &String::from_str(env, "Multi Asset Pool"),
+        &metadata,
+        &creator,
+        &1_000_000i128,
+        &(env.ledger().timestamp() + DURATION),
+        &Some(1u32),
+        &Some(vec![env, creator.clone()]),
+        &Some(vec![env, usdc.clone(), xlm.clone()]),
+        &false,
+    );
+
+    (client, usdc, xlm, pool_id)
+}
+
+fn funded_contributor(env: &Env, assets: &[(&Address, i128)]) -> Address {
+    let contributor = Address::generate(env);
+    for (asset, amount) in assets {
+        token::StellarAssetClient::new(env, asset).mint(&contributor, amount);
+    }
+    contributor
+}
+
+#[test]
+fn test_contributions_tracked_per_asset() {
+    let env = Env::default();
+    let (client, usdc, xlm, pool_id) = setup_pool(&env);
+    let alice = funded_contributor(&env, &[(&usdc, 300), (&xlm, 500)]);
+    let bob = funded_contributor(&env, &[(&xlm, 200)]);
+
+    client.contribute(&pool_id, &alice, &usdc, &300, &false);
+    client.contribute(&pool_id, &alice, &xlm, &500, &false);
+    client.contribute(&pool_id, &bob, &xlm, &200, &false);
+
+    assert_eq!(client.get_pool_contribution(&pool_id, &alice, &usdc), 300);
+    assert_eq!(client.get_pool_contribution(&pool_id, &alice, &xlm), 500);
+    assert_eq!(client.get_pool_contribution(&pool_id, &bob, &usdc), 0);
+    assert_eq!(client.get_pool_contribution(&pool_id, &bob, &xlm), 200);
+
+    let totals = client.get_pool_asset_totals(&pool_id);
+    assert_eq!(totals.get(usdc).unwrap(), 300);
+    assert_eq!(totals.get(xlm).unwrap(), 700);
+
+    // Only the target asset counts towards the amount raised
+    let metrics = client.get_pool_metrics(&pool_id);
+    assert_eq!(metrics.total_raised, 300);
+    assert_eq!(metrics.contributor_count, 2);
+}
+
+#[test]
+fn test_refund_returns_each_asset() {
+    let env = Env::default();
+    let (client, usdc, xlm, pool_id) = setup_pool(&env);
+    let alice = funded_contributor(&env, &[(&usdc, 300), (&xlm, 500)]);
+    let bob = funded_contributor(&env, &[(&xlm, 200)]);
+
+    client.contribute(&pool_id, &alice, &usdc, &300, &false);
+    client.contribute(&pool_id, &alice, &xlm, &500, &false);
+    client.contribute(&pool_id, &bob, &xlm, &200, &false);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += DURATION + REFUND_GRACE_PERIOD);
+    client.refund(&pool_id, &alice);
+
+    assert_eq!(token::Client::new(&env, &usdc).balance(&alice), 300);
+    assert_eq!(token::Client::new(&env, &xlm).balance(&alice), 500);
+    assert_eq!(token::Client::new(&env, &xlm).balance(&client.address), 200);
+    assert_eq!(client.get_pool_contribution(&pool_id, &alice, &usdc), 0);
+    assert_eq!(client.get_pool_contribution(&pool_id, &alice, &xlm), 0);
+
+    let totals = client.get_pool_asset_totals(&pool_id);
+    assert_eq!(totals.get(usdc).unwrap(), 0);
+    assert_eq!(totals.get(xlm).unwrap(), 200);
+    assert_eq!(client.get_pool_metrics(&pool_id).total_raised, 0);
+
+    let result = client.try_refund(&pool_id, &alice);
+    assert_eq!(result, Err(Ok(CrowdfundingError::NoContributionToRefund)));
+}
+
+#[test]
+fn test_disbursement_limited_to_asset_balance() {
+    let env = Env::default();
+    let (client, usdc, xlm, pool_id) = setup_pool(&env);
+    let contributor = funded_contributor(&env, &[(&usdc, 100), (&xlm, 900)]);
+
+    client.contribute(&pool_id, &contributor, &usdc, &100, &false);
+    client.contribute(&pool_id, &contributor, &xlm, &900, &false);
+
+    let creator = client.get_pool_creator(&pool_id);
+    let recipient = Address::generate(&env);
+    let result = client.try_propose_disbursement(&pool_id, &creator, &recipient, &usdc, &500);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InsufficientBalance)));
+
+    let result = client.try_propose_disbursement(&pool_id, &creator, &recipient, &xlm, &901);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InsufficientBalance)));
+}
+
+#[test]
+fn test_disbursement_pays_out_in_requested_asset() {
+    let env = Env::default();
+    let (client, usdc, xlm, pool_id) = setup_pool(&env);
+    let contributor = funded_contributor(&env, &[(&usdc, 100), (&xlm, 900)]);
+
+    client.contribute(&pool_id, &contributor, &usdc, &100, &false);
+    client.contribute(&pool_id, &contributor, &xlm, &900, &false);
+
+    let creator = client.get_pool_creator(&pool_id);
+    let recipient = Address::generate(&env);
+    let id = client.propose_disbursement(&pool_id, &creator, &recipient, &xlm, &600);
+    assert_eq!(client.get_disbursement(&pool_id, &id).asset, xlm);
+    client.execute_disbursement(&pool_id, &id, &creator);
+
+    assert_eq!(token::Client::new(&env, &xlm).balance(&recipient), 600);
+    assert_eq!(token::Client::new(&env, &usdc).balance(&recipient), 0);
+
+    let totals = client.get_pool_asset_totals(&pool_id);
+    assert_eq!(totals.get(usdc.clone()).unwrap(), 100);
+    assert_eq!(totals.get(xlm.clone()).unwrap(), 300);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Active);
+
+    let id = client.propose_disbursement(&pool_id, &creator, &recipient, &usdc, &100);
+    client.execute_disbursement(&pool_id, &id, &creator);
+    let id = client.propose_disbursement(&pool_id, &creator, &recipient, &xlm, &300);
+    client.execute_disbursement(&pool_id, &id, &creator);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Disbursed);
+}
+
+#[test]
+fn test_target_measured_in_first_accepted_asset() {
+    let env = Env::default();
+    let (client, usdc, xlm, pool_id) = setup_pool(&env);
+    let creator = client.get_pool_creator(&pool_id);
+    client.set_pool_cap_mode(&pool_id, &creator, &PoolCapMode::Reject);
+    let contributor = funded_contributor(&env, &[(&usdc, 1_000_000), (&xlm, 2_000_000)]);
+
+    // Amounts in other assets neither count towards the target nor hit the cap
+    client.contribute(&pool_id, &contributor, &xlm, &2_000_000, &false);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Active);
+    assert_eq!(client.get_pool_metrics(&pool_id).total_raised, 0);
+
+    client.contribute(&pool_id, &contributor, &usdc, &999_999, &false);
+    let result = client.try_contribute(&pool_id, &contributor, &usdc, &2, &false);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidAmount)));
+
+    client.contribute(&pool_id, &contributor, &usdc, &1, &false);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Completed);
+}
+
+#[test]
+fn test_legacy_contribution_is_refunded_and_indexed() {
+    let env = Env::default();
+    let (client, usdc, _, pool_id) = setup_pool(&env);
+    let alice = funded_contributor(&env, &[(&usdc, 400)]);
+    client.contribute(&pool_id, &alice, &usdc, &400, &false);
+
+    // Rewrite the contribution the way single-asset versions stored it
+    env.as_contract(&client.address, || {
+        let key = StorageKey::PoolContribution(pool_id, alice.clone(), usdc.clone());
+        let contribution: PoolContribution = env.storage().persistent().get(&key).unwrap();
+        env.storage().persistent().remove(&key);
+        env.storage()
+            .persistent()
+            .remove(&StorageKey::PoolContributorAssets(pool_id, alice.clone()));
+        env.storage().instance().set(
+            &(
+                Symbol::new(&env, "PoolContribution"),
+                pool_id,
+                alice.clone(),
+            ),
+            &contribution,
+        );
+    });
+
+    assert_eq!(client.get_pool_contribution(&pool_id, &alice, &usdc), 400);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += DURATION + REFUND_GRACE_PERIOD);
+    client.refund(&pool_id, &alice);
+
+    assert_eq!(token::Client::new(&env, &usdc).balance(&alice), 400);
+    assert_eq!(client.get_pool_contribution(&pool_id, &alice, &usdc), 0);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().instance().has(&(
+            Symbol::new(&env, "PoolContribution"),
+            pool_id,
+            alice.clone()
+        )));
+    });
+}
+
+#[test]
+fn test_pool_asset_queries_nonexistent_pool() {
+    let env = Env::default();
+    let (client, usdc, _, _) = setup_pool(&env);
+
+    assert_eq!(
+        client.try_get_pool_metrics(&999),
+        Err(Ok(CrowdfundingError::PoolNotFound))
+    );
+    assert_eq!(
+        client.try_get_pool_asset_totals(&999),
+        Err(Ok(CrowdfundingError::PoolNotFound))
+    );
+    assert_eq!(
+        client.try_get_pool_contribution(&999, &Address::generate(&env), &usdc),
+        Err(Ok(CrowdfundingError::PoolNotFound))
+    );
+}
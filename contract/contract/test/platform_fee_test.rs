@@ -0,0 +1,300 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, BytesN, Env, Map, String, Vec};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        types::{PlatformFeeConfig, PoolMetadata},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &token_address, &0);
+
+    (client, admin, token_address)
+}
+
+fn create_campaign(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    token_address: &Address,
+) -> BytesN<32> {
+    let campaign_id = BytesN::from_array(env, &[8u8; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Fee Campaign"),
+        &Address::generate(env),
+        &100_000,
+        &(env.ledger().timestamp() + 86400),
+        token_address,
+    );
+    campaign_id
+}
+
+fn funded(env: &Env, token_address: &Address, amount: i128) -> Address {
+    let account = Address::generate(env);
+    token::StellarAssetClient::new(env, token_address).mint(&account, &amount);
+    account
+}
+
+#[test]
+fn test_platform_fee_defaults_to_zero() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    assert_eq!(
+        client.get_platform_fee(),
+        PlatformFeeConfig {
+            fee_bps: 0,
+            caps: Map::new(&env),
+        }
+    );
+
+    let campaign_id = create_campaign(&env, &client, &token_address);
+    let donor = funded(&env, &token_address, 1_000);
+    client.donate(&campaign_id, &donor, &token_address, &1_000);
+
+    assert_eq!(client.get_total_raised(&campaign_id), 1_000);
+    assert_eq!(client.get_platform_fees(&token_address), 0);
+}
+
+#[test]
+fn test_donation_fee_is_deducted_and_withdrawable() {
+    let env = Env::default();
+    let (client, admin, token_address) = setup(&env);
+    client.set_platform_fee(&250);
+
+    let campaign_id = create_campaign(&env, &client, &token_address);
+    let donor = funded(&env, &token_address, 1_000);
+    client.donate(&campaign_id, &donor, &token_address, &1_000);
+
+    assert_eq!(client.get_total_raised(&campaign_id), 975);
+    assert_eq!(client.get_contribution(&campaign_id, &donor), 975);
+    assert_eq!(client.get_platform_fees(&token_address), 25);
+
+    client.withdraw_platform_fees(&admin, &token_address, &admin, &25);
+    assert_eq!(token::Client::new(&env, &token_address).balance(&admin), 25);
+    assert_eq!(client.get_platform_fees(&token_address), 0);
+}
+
+#[test]
+fn test_fee_min_and_max_caps() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+    client.set_platform_fee(&100);
+    client.set_platform_fee_caps(&token_address, &5, &20);
+
+    let campaign_id = create_campaign(&env, &client, &token_address);
+    let donor = funded(&env, &token_address, 10_100);
+
+    // 1% of 100 is 1, raised to the minimum of 5
+    client.donate(&campaign_id, &donor, &token_address, &100);
+    assert_eq!(client.get_platform_fees(&token_address), 5);
+
+    // 1% of 10_000 is 100, capped at 20
+    client.donate(&campaign_id, &donor, &token_address, &10_000);
+    assert_eq!(client.get_platform_fees(&token_address), 25);
+    assert_eq!(client.get_total_raised(&campaign_id), 10_075);
+}
+
+#[test]
+fn test_contribution_fee_is_deducted() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+    client.set_platform_fee(&500);
+
+    let metadata = PoolMetadata {
+        description: String::from_str(&env, "Fee pool"),
+        external_url: String::from_str(&env, ""),
+        image_hash: String::from_str(&env, ""),
+    };
+    let pool_id = client.save_pool(
+        &String::from_str(&env, "Fee Pool"),
+        &metadata,
+        &Address::generate(&env),
+        &100_000i128,
+        &(env.ledger().timestamp() + 86400),
+        &None::<u32>,
+        &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
+    );
+
+    let contributor = funded(&env, &token_address, 2_000);
+    client.contribute(&pool_id, &contributor, &token_address, &2_000, &false);
+
+    assert_eq!(client.get_pool_metrics(&pool_id).total_raised, 1_900);
+    assert_eq!(
+        client.get_pool_contribution(&pool_id, &contributor, &token_address),
+        1_900
+    );
+    assert_eq!(client.get_platform_fees(&token_address), 100);
+}
+
+#[test]
+fn test_set_platform_fee_rejects_invalid_values() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    assert_eq!(
+        client.try_set_platform_fee(&10_001),
+        Err(Ok(CrowdfundingError::InvalidFee))
+    );
+    assert_eq!(
+        client.try_set_platform_fee_caps(&token_address, &-1, &0),
+        Err(Ok(CrowdfundingError::InvalidFee))
+    );
+    assert_eq!(
+        client.try_set_platform_fee_caps(&token_address, &50, &10),
+        Err(Ok(CrowdfundingError::InvalidFee))
+    );
+    assert_eq!(
+        client.try_set_platform_fee_caps(&Address::generate(&env), &5, &20),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+}
+
+#[test]
+fn test_fee_caps_apply_per_token() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+    client.set_platform_fee(&100);
+    client.set_platform_fee_caps(&token_address, &5, &20);
+
+    let usdc = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_supported_token(&usdc);
+    client.set_platform_fee_caps(&usdc, &50_000, &200_000);
+
+    let campaign_id = create_campaign(&env, &client, &token_address);
+    let donor = funded(&env, &token_address, 10_000);
+    client.donate(&campaign_id, &donor, &token_address, &10_000);
+    assert_eq!(client.get_platform_fees(&token_address), 20);
+
+    let usdc_campaign = BytesN::from_array(&env, &[9u8; 32]);
+    client.create_campaign(
+        &usdc_campaign,
+        &String::from_str(&env, "USDC Campaign"),
+        &Address::generate(&env),
+        &100_000_000,
+        &(env.ledger().timestamp() + 86400),
+        &usdc,
+    );
+    let usdc_donor = funded(&env, &usdc, 1_000_000);
+    client.donate(&usdc_campaign, &usdc_donor, &usdc, &1_000_000);
+    assert_eq!(client.get_platform_fees(&usdc), 50_000);
+
+    // Clearing the caps leaves only the percentage
+    client.set_platform_fee_caps(&usdc, &0, &0);
+    client.donate(
+        &usdc_campaign,
+        &funded(&env, &usdc, 1_000_000),
+        &usdc,
+        &1_000_000,
+    );
+    assert_eq!(client.get_platform_fees(&usdc), 60_000);
+}
+
+#[test]
+fn test_amount_consumed_by_fee_is_rejected() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+    client.set_platform_fee_caps(&token_address, &100, &0);
+
+    let campaign_id = create_campaign(&env, &client, &token_address);
+    let donor = funded(&env, &token_address, 100);
+    assert_eq!(
+        client.try_donate(&campaign_id, &donor, &token_address, &100),
+        Err(Ok(CrowdfundingError::InvalidAmount))
+    );
+    assert_eq!(client.get_donor_count(&campaign_id), 0);
+
+    let metadata = PoolMetadata {
+        description: String::from_str(&env, "Fee pool"),
+        external_url: String::from_str(&env, ""),
+        image_hash: String::from_str(&env, ""),
+    };
+    let pool_id = client.save_pool(
+        &String::from_str(&env, "Fee Pool"),
+        &metadata,
+        &Address::generate(&env),
+        &100_000i128,
+        &(env.ledger().timestamp() + 86400),
+        &None::<u32>,
+        &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
+    );
+    assert_eq!(
+        client.try_contribute(&pool_id, &donor, &token_address, &100, &false),
+        Err(Ok(CrowdfundingError::InvalidAmount))
+    );
+    assert_eq!(client.get_pool_metrics(&pool_id).contributor_count, 0);
+    assert_eq!(
+        token::Client::new(&env, &token_address).balance(&donor),
+        100
+    );
+    assert_eq!(client.get_platform_fees(&token_address), 0);
+}
+
+#[test]
+fn test_fees_tracked_and_withdrawn_per_token() {
+    let env = Env::default();
+    let (client, admin, token_address) = setup(&env);
+    client.set_platform_fee(&1_000);
+
+    let usdc = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_supported_token(&usdc);
+
+    let campaign_id = create_campaign(&env, &client, &token_address);
+    let donor = funded(&env, &token_address, 1_000);
+    client.donate(&campaign_id, &donor, &token_address, &1_000);
+
+    let usdc_campaign = BytesN::from_array(&env, &[9u8; 32]);
+    client.create_campaign(
+        &usdc_campaign,
+        &String::from_str(&env, "USDC Campaign"),
+        &Address::generate(&env),
+        &100_000,
+        &(env.ledger().timestamp() + 86400),
+        &usdc,
+    );
+    let usdc_donor = funded(&env, &usdc, 500);
+    client.donate(&usdc_campaign, &usdc_donor, &usdc, &500);
+
+    assert_eq!(client.get_platform_fees(&token_address), 100);
+    assert_eq!(client.get_platform_fees(&usdc), 50);
+    assert_eq!(
+        client.get_platform_fee_tokens(),
+        vec![&env, token_address.clone(), usdc.clone()]
+    );
+
+    // Fees collected before a token switch stay withdrawable in their own token
+    client.set_crowdfunding_token(&usdc);
+    let treasury = Address::generate(&env);
+    client.withdraw_platform_fees(&admin, &token_address, &treasury, &100);
+    client.withdraw_platform_fees(&admin, &usdc, &treasury, &50);
+
+    assert_eq!(
+        token::Client::new(&env, &token_address).balance(&treasury),
+        100
+    );
+    assert_eq!(token::Client::new(&env, &usdc).balance(&treasury), 50);
+    assert_eq!(
+        client.try_withdraw_platform_fees(&admin, &usdc, &treasury, &1),
+        Err(Ok(CrowdfundingError::InsufficientFees))
+    );
+}
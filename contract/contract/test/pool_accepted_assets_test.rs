@@ -0,0 +1,174 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String, Vec};
+
+use crate::{
+    base::{errors::CrowdfundingError, types::PoolMetadata},
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&Address::generate(env), &token_address, &0);
+
+    (client, token_address)
+}
+
+fn new_asset(env: &Env) -> Address {
+    env.register_stellar_asset_contract_v2(Address::generate(env))
+        .address()
+}
+
+fn new_supported_asset(env: &Env, client: &CrowdfundingContractClient) -> Address {
+    let asset = new_asset(env);
+    client.add_supported_token(&asset);
+    asset
+}
+
+fn try_save_pool(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    accepted_assets: Option<Vec<Address>>,
+) -> Result<u64, Result<CrowdfundingError, soroban_sdk::InvokeError>> {
+    let metadata = PoolMetadata {
+        description: String::from_str(env, "Allowlisted pool"),
+        external_url: String::from_str(env, ""),
+        image_hash: String::from_str(env, ""),
+    };
+    client
+        .try_save_pool(
+            &String::from_str(env, "Allowlisted Pool"),
+            &metadata,
+            &Address::generate(env),
+            &10_000i128,
+            &(env.ledger().timestamp() + 86400),
+            &None::<u32>,
+            &None::<Vec<Address>>,
+            &accepted_assets,
+            &false,
+        )
+        .map(|id| id.unwrap())
+}
+
+fn contribute(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    pool_id: u64,
+    asset: &Address,
+) -> Result<(), Result<CrowdfundingError, soroban_sdk::InvokeError>> {
+    let contributor = Address::generate(env);
+    token::StellarAssetClient::new(env, asset).mint(&contributor, &100);
+    client
+        .try_contribute(&pool_id, &contributor, asset, &100, &false)
+        .map(|result| result.unwrap())
+}
+
+#[test]
+fn test_pool_defaults_to_crowdfunding_token() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    let pool_id = try_save_pool(&env, &client, None).unwrap();
+
+    assert_eq!(
+        client.get_pool_accepted_assets(&pool_id),
+        vec![&env, token_address.clone()]
+    );
+    assert_eq!(contribute(&env, &client, pool_id, &token_address), Ok(()));
+    assert_eq!(
+        contribute(&env, &client, pool_id, &new_asset(&env)),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+}
+
+#[test]
+fn test_default_pool_keeps_its_asset_when_token_changes() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    let pool_id = try_save_pool(&env, &client, None).unwrap();
+    assert_eq!(contribute(&env, &client, pool_id, &token_address), Ok(()));
+
+    let replacement = new_asset(&env);
+    client.set_crowdfunding_token(&replacement);
+
+    assert_eq!(
+        client.get_pool_accepted_assets(&pool_id),
+        vec![&env, token_address.clone()]
+    );
+    assert_eq!(contribute(&env, &client, pool_id, &token_address), Ok(()));
+    assert_eq!(
+        contribute(&env, &client, pool_id, &replacement),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+    assert_eq!(client.get_pool_metrics(&pool_id).total_raised, 200);
+}
+
+#[test]
+fn test_pool_accepts_only_declared_assets() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    let usdc = new_supported_asset(&env, &client);
+    let xlm = new_supported_asset(&env, &client);
+    let pool_id =
+        try_save_pool(&env, &client, Some(vec![&env, usdc.clone(), xlm.clone()])).unwrap();
+
+    assert_eq!(
+        client.get_pool_accepted_assets(&pool_id),
+        vec![&env, usdc.clone(), xlm.clone()]
+    );
+    assert_eq!(contribute(&env, &client, pool_id, &usdc), Ok(()));
+    assert_eq!(contribute(&env, &client, pool_id, &xlm), Ok(()));
+    assert_eq!(
+        contribute(&env, &client, pool_id, &token_address),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+}
+
+#[test]
+fn test_delisted_asset_rejected_even_if_accepted() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let usdc = new_supported_asset(&env, &client);
+    let pool_id = try_save_pool(&env, &client, Some(vec![&env, usdc.clone()])).unwrap();
+
+    client.remove_supported_token(&usdc);
+    assert_eq!(
+        contribute(&env, &client, pool_id, &usdc),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+}
+
+#[test]
+fn test_invalid_asset_allowlists_rejected() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+
+    assert_eq!(
+        try_save_pool(&env, &client, Some(Vec::new(&env))),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+    assert_eq!(
+        try_save_pool(
+            &env,
+            &client,
+            Some(vec![&env, token_address.clone(), token_address])
+        ),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+}
+
+#[test]
+fn test_accepted_assets_nonexistent_pool() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    assert_eq!(
+        client.try_get_pool_accepted_assets(&999),
+        Err(Ok(CrowdfundingError::PoolNotFound))
+    );
+}
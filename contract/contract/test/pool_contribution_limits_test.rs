@@ -0,0 +1,215 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String, Vec,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        types::{PoolCapMode, PoolMetadata, PoolState},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+const TARGET: i128 = 1_000;
+const DURATION: u64 = 86400;
+
+fn setup_pool(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address, u64) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.initialize(&admin, &token_address, &0);
+
+    let creator = Address::generate(env);
+    let metadata = PoolMetadata {
+        description: String::from_str(env, "Capped pool"),
+        external_url: String::from_str(env, ""),
+        image_hash: String::from_str(env, ""),
+    };
+    let pool_id = client.save_pool(
+        &String::from_str(env, "Capped Pool"),
+        &metadata,
+        &creator,
+        &TARGET,
+        &(env.ledger().timestamp() + DURATION),
+        &None::<u32>,
+        &None::<Vec<Address>>,
+        &None::<Vec<Address>>,
+        &false,
+    );
+
+    (client, creator, token_address, pool_id)
+}
+
+fn funded_contributor(env: &Env, token_address: &Address, amount: i128) -> Address {
+    let contributor = Address::generate(env);
+    token::StellarAssetClient::new(env, token_address).mint(&contributor, &amount);
+    contributor
+}
+
+#[test]
+fn test_contribute_after_deadline_fails() {
+    let env = Env::default();
+    let (client, _, token_address, pool_id) = setup_pool(&env);
+    let contributor = funded_contributor(&env, &token_address, 100);
+
+    env.ledger().with_mut(|li| li.timestamp += DURATION);
+
+    let result = client.try_contribute(&pool_id, &contributor, &token_address, &100, &false);
+    assert_eq!(result, Err(Ok(CrowdfundingError::CampaignExpired)));
+}
+
+#[test]
+fn test_reaching_target_completes_pool() {
+    let env = Env::default();
+    let (client, _, token_address, pool_id) = setup_pool(&env);
+    let contributor = funded_contributor(&env, &token_address, TARGET);
+
+    client.contribute(&pool_id, &contributor, &token_address, &600, &false);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Active);
+
+    client.contribute(&pool_id, &contributor, &token_address, &400, &false);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Completed);
+
+    let history = client.get_pool_state_history(&pool_id);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().changed_by, client.address);
+
+    let late = funded_contributor(&env, &token_address, 10);
+    let result = client.try_contribute(&pool_id, &late, &token_address, &10, &false);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidPoolState)));
+}
+
+#[test]
+fn test_soft_cap_allows_overshoot() {
+    let env = Env::default();
+    let (client, _, token_address, pool_id) = setup_pool(&env);
+    let contributor = funded_contributor(&env, &token_address, 1_500);
+
+    assert_eq!(client.get_pool_cap_mode(&pool_id), PoolCapMode::Soft);
+    client.contribute(&pool_id, &contributor, &token_address, &1_500, &false);
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&client.address), 1_500);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Completed);
+}
+
+#[test]
+fn test_reject_cap_rejects_overshoot() {
+    let env = Env::default();
+    let (client, creator, token_address, pool_id) = setup_pool(&env);
+    let contributor = funded_contributor(&env, &token_address, 1_500);
+
+    client.set_pool_cap_mode(&pool_id, &creator, &PoolCapMode::Reject);
+
+    client.contribute(&pool_id, &contributor, &token_address, &800, &false);
+    let result = client.try_contribute(&pool_id, &contributor, &token_address, &300, &false);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidAmount)));
+
+    client.contribute(&pool_id, &contributor, &token_address, &200, &false);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Completed);
+}
+
+#[test]
+fn test_trim_cap_trims_overshoot() {
+    let env = Env::default();
+    let (client, creator, token_address, pool_id) = setup_pool(&env);
+    let contributor = funded_contributor(&env, &token_address, 1_500);
+
+    client.set_pool_cap_mode(&pool_id, &creator, &PoolCapMode::Trim);
+
+    client.contribute(&pool_id, &contributor, &token_address, &800, &false);
+    client.contribute(&pool_id, &contributor, &token_address, &700, &false);
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&client.address), TARGET);
+    assert_eq!(token_client.balance(&contributor), 500);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Completed);
+}
+
+#[test]
+fn test_trim_cap_leaves_room_for_platform_fee() {
+    let env = Env::default();
+    let (client, creator, token_address, pool_id) = setup_pool(&env);
+    let contributor = funded_contributor(&env, &token_address, 1_500);
+
+    client.set_platform_fee(&500);
+    client.set_platform_fee_caps(&token_address, &10, &0);
+    client.set_pool_cap_mode(&pool_id, &creator, &PoolCapMode::Trim);
+
+    // 800 gross leaves 760 after the 5% fee
+    client.contribute(&pool_id, &contributor, &token_address, &800, &false);
+    // 252 gross leaves exactly the remaining 240 after a 12 fee
+    client.contribute(&pool_id, &contributor, &token_address, &700, &false);
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&contributor), 448);
+    assert_eq!(client.get_pool_metrics(&pool_id).total_raised, TARGET);
+    assert_eq!(client.get_platform_fees(&token_address), 52);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Completed);
+}
+
+#[test]
+fn test_trim_cap_reaches_target_with_min_fee() {
+    let env = Env::default();
+    let (client, creator, token_address, pool_id) = setup_pool(&env);
+    let contributor = funded_contributor(&env, &token_address, 1_500);
+
+    client.set_platform_fee_caps(&token_address, &25, &0);
+    client.set_pool_cap_mode(&pool_id, &creator, &PoolCapMode::Trim);
+
+    client.contribute(&pool_id, &contributor, &token_address, &990, &false);
+    client.contribute(&pool_id, &contributor, &token_address, &100, &false);
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&contributor), 1_500 - 990 - 60);
+    assert_eq!(client.get_pool_metrics(&pool_id).total_raised, TARGET);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Completed);
+}
+
+#[test]
+fn test_reject_cap_compares_net_amount() {
+    let env = Env::default();
+    let (client, creator, token_address, pool_id) = setup_pool(&env);
+    let contributor = funded_contributor(&env, &token_address, 1_500);
+
+    client.set_platform_fee(&500);
+    client.set_platform_fee_caps(&token_address, &10, &0);
+    client.set_pool_cap_mode(&pool_id, &creator, &PoolCapMode::Reject);
+
+    client.contribute(&pool_id, &contributor, &token_address, &800, &false);
+    let result = client.try_contribute(&pool_id, &contributor, &token_address, &253, &false);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidAmount)));
+
+    client.contribute(&pool_id, &contributor, &token_address, &252, &false);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Completed);
+}
+
+#[test]
+fn test_set_pool_cap_mode_unauthorized() {
+    let env = Env::default();
+    let (client, _, _, pool_id) = setup_pool(&env);
+
+    let result =
+        client.try_set_pool_cap_mode(&pool_id, &Address::generate(&env), &PoolCapMode::Trim);
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+}
+
+#[test]
+fn test_set_pool_cap_mode_requires_active_pool() {
+    let env = Env::default();
+    let (client, creator, _, pool_id) = setup_pool(&env);
+
+    client.update_pool_state(&pool_id, &PoolState::Cancelled, &creator);
+
+    let result = client.try_set_pool_cap_mode(&pool_id, &creator, &PoolCapMode::Reject);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidPoolState)));
+}
@@ -0,0 +1,171 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String, Vec,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        types::{PoolMetadata, PoolState},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+fn setup_pool(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address, u64) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.initialize(&admin, &token_address, &0);
+
+    let creator = Address::generate(env);
+    let metadata = PoolMetadata {
+        description: String::from_str(env, "Lifecycle pool"),
+        external_url: String::from_str(env, ""),
+        image_hash: String::from_str(env, ""),
+    };
+    let pool_id = client.save_pool(
+        &String::from_str(env, "Lifecycle Pool"),
+        &metadata,
+        &creator,
+        &10_000i128,
+        &(env.ledger().timestamp() + 86400),
+        &Some(1u32),
+        &Some(vec![env, creator.clone()]),
+        &None::<Vec<Address>>,
+        &false,
+    );
+
+    (client, admin, creator, pool_id)
+}
+
+#[test]
+fn test_new_pool_state_and_allowed_transitions() {
+    let env = Env::default();
+    let (client, _, _, pool_id) = setup_pool(&env);
+
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Active);
+    assert_eq!(
+        client.allowed_transitions(&pool_id),
+        vec![
+            &env,
+            PoolState::Paused,
+            PoolState::Completed,
+            PoolState::Cancelled,
+        ]
+    );
+    assert!(client.get_pool_state_history(&pool_id).is_empty());
+}
+
+#[test]
+fn test_allowed_transitions_follow_state() {
+    let env = Env::default();
+    let (client, _, creator, pool_id) = setup_pool(&env);
+
+    client.update_pool_state(&pool_id, &PoolState::Cancelled, &creator);
+    assert_eq!(
+        client.allowed_transitions(&pool_id),
+        vec![&env, PoolState::Closed]
+    );
+
+    client.close_pool(&pool_id, &creator);
+    assert_eq!(client.allowed_transitions(&pool_id), Vec::new(&env));
+}
+
+#[test]
+fn test_state_history_records_actor_and_timestamp() {
+    let env = Env::default();
+    let (client, admin, creator, pool_id) = setup_pool(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.update_pool_state(&pool_id, &PoolState::Paused, &creator);
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.update_pool_state(&pool_id, &PoolState::Active, &admin);
+
+    let history = client.get_pool_state_history(&pool_id);
+    assert_eq!(history.len(), 2);
+
+    let first = history.get(0).unwrap();
+    assert_eq!(first.from, PoolState::Active);
+    assert_eq!(first.to, PoolState::Paused);
+    assert_eq!(first.changed_by, creator);
+    assert_eq!(first.changed_at, 100);
+
+    let second = history.get(1).unwrap();
+    assert_eq!(second.from, PoolState::Paused);
+    assert_eq!(second.to, PoolState::Active);
+    assert_eq!(second.changed_by, admin);
+    assert_eq!(second.changed_at, 200);
+}
+
+#[test]
+fn test_state_history_includes_disbursement_and_close() {
+    let env = Env::default();
+    let (client, _, creator, pool_id) = setup_pool(&env);
+
+    let token_address = client.get_crowdfunding_token();
+    let contributor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_address).mint(&contributor, &500);
+    client.contribute(&pool_id, &contributor, &token_address, &500, &false);
+
+    let disbursement_id = client.propose_disbursement(
+        &pool_id,
+        &creator,
+        &Address::generate(&env),
+        &token_address,
+        &500,
+    );
+    client.execute_disbursement(&pool_id, &disbursement_id, &creator);
+    client.close_pool(&pool_id, &creator);
+
+    let history = client.get_pool_state_history(&pool_id);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().to, PoolState::Disbursed);
+    assert_eq!(history.get(1).unwrap().to, PoolState::Closed);
+    assert_eq!(client.get_pool_state(&pool_id), PoolState::Closed);
+}
+
+#[test]
+fn test_refund_blocked_after_close() {
+    let env = Env::default();
+    let (client, _, creator, pool_id) = setup_pool(&env);
+
+    let token_address = client.get_crowdfunding_token();
+    let contributor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_address).mint(&contributor, &500);
+    client.contribute(&pool_id, &contributor, &token_address, &500, &false);
+
+    client.update_pool_state(&pool_id, &PoolState::Cancelled, &creator);
+    client.close_pool(&pool_id, &creator);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += 86400 + 604800 + 1);
+    let result = client.try_refund(&pool_id, &contributor);
+    assert_eq!(result, Err(Ok(CrowdfundingError::PoolAlreadyClosed)));
+}
+
+#[test]
+fn test_state_queries_nonexistent_pool() {
+    let env = Env::default();
+    let (client, _, _, _) = setup_pool(&env);
+
+    assert_eq!(
+        client.try_get_pool_state(&999),
+        Err(Ok(CrowdfundingError::PoolNotFound))
+    );
+    assert_eq!(
+        client.try_allowed_transitions(&999),
+        Err(Ok(CrowdfundingError::PoolNotFound))
+    );
+    assert_eq!(
+        client.try_get_pool_state_history(&999),
+        Err(Ok(CrowdfundingError::PoolNotFound))
+    );
+}
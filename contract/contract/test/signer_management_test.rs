@@ -0,0 +1,202 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+use crate::{
+    base::{errors::CrowdfundingError, types::PoolMetadata},
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+fn setup_multisig_pool(
+    env: &Env,
+    required_signatures: u32,
+    signer_count: u32,
+) -> (CrowdfundingContractClient<'_>, u64, Vec<Address>) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.initialize(&admin, &token_address, &0);
+
+    let mut signers = Vec::new(env);
+    for _ in 0..signer_count {
+        signers.push_back(Address::generate(env));
+    }
+
+    let metadata = PoolMetadata {
+        description: String::from_str(env, "Signer rotation pool"),
+        external_url: String::from_str(env, ""),
+        image_hash: String::from_str(env, ""),
+    };
+    let pool_id = client.save_pool(
+        &String::from_str(env, "Treasury Pool"),
+        &metadata,
+        &Address::generate(env),
+        &10_000i128,
+        &(env.ledger().timestamp() + 86400),
+        &Some(required_signatures),
+        &Some(signers.clone()),
+        &None::<Vec<Address>>,
+        &false,
+    );
+
+    (client, pool_id, signers)
+}
+
+#[test]
+fn test_add_signer_with_quorum() {
+    let env = Env::default();
+    let (client, pool_id, signers) = setup_multisig_pool(&env, 2, 2);
+    let new_signer = Address::generate(&env);
+
+    client.add_signer(&pool_id, &signers, &new_signer);
+
+    let config = client.get_multisig_config(&pool_id);
+    assert_eq!(config.signers.len(), 3);
+    assert!(config.signers.contains(&new_signer));
+    assert_eq!(config.required_signatures, 2);
+}
+
+#[test]
+fn test_add_existing_signer() {
+    let env = Env::default();
+    let (client, pool_id, signers) = setup_multisig_pool(&env, 1, 2);
+
+    let approvers = vec![&env, signers.get(0).unwrap()];
+    let result = client.try_add_signer(&pool_id, &approvers, &signers.get(1).unwrap());
+    assert_eq!(result, Err(Ok(CrowdfundingError::SignerAlreadyExists)));
+}
+
+#[test]
+fn test_signer_change_without_quorum() {
+    let env = Env::default();
+    let (client, pool_id, signers) = setup_multisig_pool(&env, 2, 3);
+
+    let approvers = vec![&env, signers.get(0).unwrap()];
+    let result = client.try_add_signer(&pool_id, &approvers, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(CrowdfundingError::InsufficientApprovals)));
+}
+
+#[test]
+fn test_signer_change_with_duplicate_approver() {
+    let env = Env::default();
+    let (client, pool_id, signers) = setup_multisig_pool(&env, 2, 3);
+
+    let signer = signers.get(0).unwrap();
+    let approvers = vec![&env, signer.clone(), signer];
+    let result = client.try_add_signer(&pool_id, &approvers, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(CrowdfundingError::AlreadyApproved)));
+}
+
+#[test]
+fn test_signer_change_with_outside_approver() {
+    let env = Env::default();
+    let (client, pool_id, signers) = setup_multisig_pool(&env, 2, 3);
+
+    let approvers = vec![&env, signers.get(0).unwrap(), Address::generate(&env)];
+    let result = client.try_add_signer(&pool_id, &approvers, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(CrowdfundingError::NotAuthorizedSigner)));
+}
+
+#[test]
+fn test_remove_signer() {
+    let env = Env::default();
+    let (client, pool_id, signers) = setup_multisig_pool(&env, 2, 3);
+    let removed = signers.get(2).unwrap();
+
+    let approvers = vec![&env, signers.get(0).unwrap(), signers.get(1).unwrap()];
+    client.remove_signer(&pool_id, &approvers, &removed);
+
+    let config = client.get_multisig_config(&pool_id);
+    assert_eq!(config.signers.len(), 2);
+    assert!(!config.signers.contains(&removed));
+}
+
+#[test]
+fn test_remove_unknown_signer() {
+    let env = Env::default();
+    let (client, pool_id, signers) = setup_multisig_pool(&env, 1, 2);
+
+    let result = client.try_remove_signer(&pool_id, &signers, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(CrowdfundingError::SignerNotFound)));
+}
+
+#[test]
+fn test_remove_last_signer() {
+    let env = Env::default();
+    let (client, pool_id, signers) = setup_multisig_pool(&env, 1, 1);
+
+    let result = client.try_remove_signer(&pool_id, &signers, &signers.get(0).unwrap());
+    assert_eq!(result, Err(Ok(CrowdfundingError::CannotRemoveLastSigner)));
+}
+
+#[test]
+fn test_remove_signer_below_threshold() {
+    let env = Env::default();
+    let (client, pool_id, signers) = setup_multisig_pool(&env, 2, 2);
+
+    let result = client.try_remove_signer(&pool_id, &signers, &signers.get(1).unwrap());
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidSignerCount)));
+}
+
+#[test]
+fn test_set_required_signatures() {
+    let env = Env::default();
+    let (client, pool_id, signers) = setup_multisig_pool(&env, 1, 3);
+
+    let approvers = vec![&env, signers.get(0).unwrap()];
+    client.set_required_signatures(&pool_id, &approvers, &3);
+    assert_eq!(client.get_multisig_config(&pool_id).required_signatures, 3);
+
+    // The raised threshold now applies to further changes
+    let result = client.try_set_required_signatures(&pool_id, &approvers, &1);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InsufficientApprovals)));
+
+    client.set_required_signatures(&pool_id, &signers, &1);
+    assert_eq!(client.get_multisig_config(&pool_id).required_signatures, 1);
+}
+
+#[test]
+fn test_set_required_signatures_invalid() {
+    let env = Env::default();
+    let (client, pool_id, signers) = setup_multisig_pool(&env, 1, 3);
+
+    let result = client.try_set_required_signatures(&pool_id, &signers, &0);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidMultiSigConfig)));
+
+    let result = client.try_set_required_signatures(&pool_id, &signers, &4);
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidMultiSigConfig)));
+}
+
+#[test]
+fn test_removed_signer_approval_no_longer_counts() {
+    let env = Env::default();
+    let (client, pool_id, signers) = setup_multisig_pool(&env, 2, 3);
+    let token_address = client.get_crowdfunding_token();
+
+    let contributor = Address::generate(&env);
+    soroban_sdk::token::StellarAssetClient::new(&env, &token_address).mint(&contributor, &1_000);
+    client.contribute(&pool_id, &contributor, &token_address, &1_000, &false);
+
+    let leaving = signers.get(2).unwrap();
+    let disbursement_id = client.propose_disbursement(
+        &pool_id,
+        &leaving,
+        &Address::generate(&env),
+        &token_address,
+        &500,
+    );
+    client.approve_disbursement(&pool_id, &disbursement_id, &signers.get(0).unwrap());
+
+    let approvers = vec![&env, signers.get(0).unwrap(), signers.get(1).unwrap()];
+    client.remove_signer(&pool_id, &approvers, &leaving);
+
+    let result =
+        client.try_execute_disbursement(&pool_id, &disbursement_id, &signers.get(0).unwrap());
+    assert_eq!(result, Err(Ok(CrowdfundingError::InsufficientApprovals)));
+}
@@ -0,0 +1,258 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, BytesN, Env, String, Symbol, Vec};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        types::{CampaignDetails, PoolConfig, PoolMetadata, PoolMetrics, StorageKey},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &token_address, &0);
+
+    (client, admin, token_address)
+}
+
+fn create_campaign(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    token_address: &Address,
+) -> BytesN<32> {
+    let campaign_id = BytesN::from_array(env, &[5u8; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "Storage Campaign"),
+        &Address::generate(env),
+        &100_000,
+        &(env.ledger().timestamp() + 86400),
+        token_address,
+    );
+    campaign_id
+}
+
+fn create_pool(env: &Env, client: &CrowdfundingContractClient) -> u64 {
+    let config = PoolConfig {
+        name: String::from_str(env, "Storage Pool"),
+        description: String::from_str(env, "Pool used for storage layout checks"),
+        target_amount: 1_000_000,
+        is_private: false,
+        duration: 86400,
+        created_at: env.ledger().timestamp(),
+    };
+    client.create_pool(&Address::generate(env), &config, &None, &false)
+}
+
+fn funded(env: &Env, token_address: &Address, amount: i128) -> Address {
+    let account = Address::generate(env);
+    token::StellarAssetClient::new(env, token_address).mint(&account, &amount);
+    account
+}
+
+#[test]
+fn test_campaign_records_use_persistent_storage() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+    let campaign_id = create_campaign(&env, &client, &token_address);
+    let donor = funded(&env, &token_address, 500);
+    client.donate(&campaign_id, &donor, &token_address, &500);
+
+    env.as_contract(&client.address, || {
+        let keys = [
+            StorageKey::Campaign(campaign_id.clone()),
+            StorageKey::CampaignMetrics(campaign_id.clone()),
+            StorageKey::CampaignDonor(campaign_id.clone(), donor.clone()),
+            StorageKey::Contribution(campaign_id.clone(), donor.clone()),
+        ];
+        for key in keys.iter() {
+            assert!(env.storage().persistent().has(key));
+            assert!(!env.storage().instance().has(key));
+        }
+        assert!(!env.storage().instance().has(&(campaign_id.clone(),)));
+
+        assert!(env.storage().instance().has(&StorageKey::Admin));
+        assert!(env.storage().instance().has(&StorageKey::CrowdfundingToken));
+    });
+}
+
+#[test]
+fn test_pool_records_use_persistent_storage() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+    let pool_id = create_pool(&env, &client);
+    let contributor = funded(&env, &token_address, 300);
+    client.contribute(&pool_id, &contributor, &token_address, &300, &false);
+
+    env.as_contract(&client.address, || {
+        let keys = [
+            StorageKey::Pool(pool_id),
+            StorageKey::PoolCreator(pool_id),
+            StorageKey::PoolMetrics(pool_id),
+            StorageKey::PoolAssetTotals(pool_id),
+            StorageKey::PoolContribution(pool_id, contributor.clone(), token_address.clone()),
+            StorageKey::PoolContributorAssets(pool_id, contributor.clone()),
+        ];
+        for key in keys.iter() {
+            assert!(env.storage().persistent().has(key));
+            assert!(!env.storage().instance().has(key));
+        }
+        assert!(env.storage().instance().has(&StorageKey::NextPoolId));
+    });
+}
+
+#[test]
+fn test_legacy_campaign_is_readable_and_moves_on_write() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+    let campaign_id = create_campaign(&env, &client, &token_address);
+
+    // Rewrite the campaign the way earlier versions stored it.
+    env.as_contract(&client.address, || {
+        let key = StorageKey::Campaign(campaign_id.clone());
+        let campaign: CampaignDetails = env.storage().persistent().get(&key).unwrap();
+        env.storage().persistent().remove(&key);
+        env.storage()
+            .instance()
+            .set(&(campaign_id.clone(),), &campaign);
+    });
+
+    assert_eq!(client.get_campaign(&campaign_id).id, campaign_id);
+
+    let donor = funded(&env, &token_address, 200);
+    client.donate(&campaign_id, &donor, &token_address, &200);
+    assert_eq!(client.get_total_raised(&campaign_id), 200);
+
+    env.as_contract(&client.address, || {
+        assert!(env
+            .storage()
+            .persistent()
+            .has(&StorageKey::Campaign(campaign_id.clone())));
+        assert!(!env.storage().instance().has(&(campaign_id.clone(),)));
+    });
+}
+
+#[test]
+fn test_migrate_storage_moves_legacy_entries() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+    let campaign_id = create_campaign(&env, &client, &token_address);
+    let pool_id = create_pool(&env, &client);
+
+    env.as_contract(&client.address, || {
+        let persistent = env.storage().persistent();
+        let instance = env.storage().instance();
+
+        let campaign_key = StorageKey::Campaign(campaign_id.clone());
+        let campaign: CampaignDetails = persistent.get(&campaign_key).unwrap();
+        persistent.remove(&campaign_key);
+        instance.set(&(campaign_id.clone(),), &campaign);
+
+        let metrics_key = StorageKey::PoolMetrics(pool_id);
+        let metrics: PoolMetrics = persistent.get(&metrics_key).unwrap();
+        persistent.remove(&metrics_key);
+        instance.set(&metrics_key, &metrics);
+    });
+
+    let migrated = client.migrate_storage(&vec![&env, campaign_id.clone()], &vec![&env, pool_id]);
+    assert_eq!(migrated, 2);
+
+    env.as_contract(&client.address, || {
+        assert!(env
+            .storage()
+            .persistent()
+            .has(&StorageKey::Campaign(campaign_id.clone())));
+        assert!(env
+            .storage()
+            .persistent()
+            .has(&StorageKey::PoolMetrics(pool_id)));
+        assert!(!env.storage().instance().has(&(campaign_id.clone(),)));
+        assert!(!env
+            .storage()
+            .instance()
+            .has(&StorageKey::PoolMetrics(pool_id)));
+    });
+
+    // Already migrated entries are left alone.
+    assert_eq!(
+        client.migrate_storage(&vec![&env, campaign_id], &vec![&env, pool_id]),
+        0
+    );
+}
+
+#[test]
+fn test_migrate_storage_credits_legacy_platform_fees() {
+    let env = Env::default();
+    let (client, _, token_address) = setup(&env);
+
+    // Fees used to be a single balance under the unit `PlatformFees` key
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&(Symbol::new(&env, "PlatformFees"),), &750i128);
+    });
+    assert_eq!(client.get_platform_fees(&token_address), 0);
+
+    let migrated = client.migrate_storage(&Vec::new(&env), &Vec::new(&env));
+    assert_eq!(migrated, 1);
+    assert_eq!(client.get_platform_fees(&token_address), 750);
+    assert_eq!(
+        client.get_platform_fee_tokens(),
+        vec![&env, token_address.clone()]
+    );
+
+    env.as_contract(&client.address, || {
+        assert!(!env
+            .storage()
+            .instance()
+            .has(&(Symbol::new(&env, "PlatformFees"),)));
+    });
+    assert_eq!(client.migrate_storage(&Vec::new(&env), &Vec::new(&env)), 0);
+}
+
+#[test]
+fn test_migrate_storage_requires_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    let result = client.try_migrate_storage(&Vec::new(&env), &Vec::new(&env));
+    assert_eq!(result, Err(Ok(CrowdfundingError::NotInitialized)));
+}
+
+#[test]
+fn test_pool_metadata_survives_layout_change() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+    let creator = Address::generate(&env);
+    let metadata = PoolMetadata {
+        description: String::from_str(&env, "Persistent metadata"),
+        external_url: String::from_str(&env, "https://example.org"),
+        image_hash: String::from_str(&env, ""),
+    };
+    let pool_id = client.save_pool(
+        &String::from_str(&env, "Metadata Pool"),
+        &metadata,
+        &creator,
+        &10_000i128,
+        &(env.ledger().timestamp() + 86400),
+        &None,
+        &None,
+        &None,
+        &false,
+    );
+
+    let (description, external_url, _) = client.get_pool_metadata(&pool_id);
+    assert_eq!(description, metadata.description);
+    assert_eq!(external_url, metadata.external_url);
+}
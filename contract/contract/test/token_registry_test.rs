@@ -0,0 +1,155 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, BytesN, Env, String, Vec};
+
+use crate::{
+    base::{errors::CrowdfundingError, types::PoolMetadata},
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&Address::generate(env), &token_address, &0);
+
+    (client, token_address)
+}
+
+fn new_asset(env: &Env) -> Address {
+    env.register_stellar_asset_contract_v2(Address::generate(env))
+        .address()
+}
+
+#[test]
+fn test_initialize_registers_crowdfunding_token() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+
+    let tokens = client.get_supported_tokens();
+    assert_eq!(tokens.len(), 1);
+
+    let info = tokens.get(0).unwrap();
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(info.address, token_address);
+    assert_eq!(info.decimals, token_client.decimals());
+    assert_eq!(info.symbol, token_client.symbol());
+    assert!(client.is_token_supported(&token_address));
+}
+
+#[test]
+fn test_add_and_remove_supported_token() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    let asset = new_asset(&env);
+
+    assert!(!client.is_token_supported(&asset));
+    client.add_supported_token(&asset);
+    assert!(client.is_token_supported(&asset));
+    assert_eq!(client.get_supported_tokens().len(), 2);
+    assert_eq!(client.get_supported_tokens().get(1).unwrap().address, asset);
+
+    client.remove_supported_token(&asset);
+    assert!(!client.is_token_supported(&asset));
+    assert_eq!(client.get_supported_tokens().len(), 1);
+    assert_eq!(
+        client.get_supported_tokens().get(0).unwrap().address,
+        token_address
+    );
+}
+
+#[test]
+fn test_registry_rejects_invalid_changes() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+
+    assert_eq!(
+        client.try_add_supported_token(&token_address),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+    assert_eq!(
+        client.try_remove_supported_token(&token_address),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+    assert_eq!(
+        client.try_remove_supported_token(&new_asset(&env)),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+}
+
+#[test]
+fn test_set_crowdfunding_token_registers_token() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let asset = new_asset(&env);
+
+    client.set_crowdfunding_token(&asset);
+    assert!(client.is_token_supported(&asset));
+    assert_eq!(
+        client.try_remove_supported_token(&asset),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+}
+
+#[test]
+fn test_create_campaign_requires_supported_token() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    let result = client.try_create_campaign(
+        &BytesN::from_array(&env, &[7u8; 32]),
+        &String::from_str(&env, "Unlisted"),
+        &Address::generate(&env),
+        &1_000,
+        &(env.ledger().timestamp() + 86400),
+        &new_asset(&env),
+    );
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidToken)));
+}
+
+#[test]
+fn test_pools_require_supported_tokens() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let asset = new_asset(&env);
+
+    let metadata = PoolMetadata {
+        description: String::from_str(&env, "Registry pool"),
+        external_url: String::from_str(&env, ""),
+        image_hash: String::from_str(&env, ""),
+    };
+    let save = |accepted_assets: &Vec<Address>| {
+        client.try_save_pool(
+            &String::from_str(&env, "Registry Pool"),
+            &metadata,
+            &Address::generate(&env),
+            &10_000i128,
+            &(env.ledger().timestamp() + 86400),
+            &None::<u32>,
+            &None::<Vec<Address>>,
+            &Some(accepted_assets.clone()),
+            &false,
+        )
+    };
+
+    assert_eq!(
+        save(&vec![&env, asset.clone()]),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+
+    client.add_supported_token(&asset);
+    let pool_id = save(&vec![&env, asset.clone()]).unwrap().unwrap();
+
+    // Delisting a token stops new contributions in it
+    let contributor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &asset).mint(&contributor, &100);
+    client.remove_supported_token(&asset);
+    assert_eq!(
+        client.try_contribute(&pool_id, &contributor, &asset, &100, &false),
+        Err(Ok(CrowdfundingError::InvalidToken))
+    );
+}
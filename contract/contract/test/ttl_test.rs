@@ -0,0 +1,252 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{
+        storage::{Instance as _, Persistent as _},
+        Address as _, Ledger,
+    },
+    token, vec, Address, BytesN, Env, String, Vec,
+};
+
+use crate::{
+    base::{
+        errors::CrowdfundingError,
+        types::{PoolConfig, StorageKey, TtlConfig, DEFAULT_TTL_EXTEND_TO},
+    },
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+const THRESHOLD: u32 = 5_000;
+const EXTEND_TO: u32 = 10_000;
+
+fn setup(env: &Env) -> (CrowdfundingContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &token_address, &0);
+
+    (client, token_address)
+}
+
+fn create_campaign(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    token_address: &Address,
+) -> BytesN<32> {
+    let campaign_id = BytesN::from_array(env, &[6u8; 32]);
+    client.create_campaign(
+        &campaign_id,
+        &String::from_str(env, "TTL Campaign"),
+        &Address::generate(env),
+        &100_000,
+        &(env.ledger().timestamp() + 86400),
+        token_address,
+    );
+    campaign_id
+}
+
+fn create_pool(env: &Env, client: &CrowdfundingContractClient) -> u64 {
+    let config = PoolConfig {
+        name: String::from_str(env, "TTL Pool"),
+        description: String::from_str(env, "Pool used for TTL checks"),
+        target_amount: 1_000_000,
+        is_private: false,
+        duration: 86400,
+        created_at: env.ledger().timestamp(),
+    };
+    client.create_pool(&Address::generate(env), &config, &None, &false)
+}
+
+fn funded(env: &Env, token_address: &Address, amount: i128) -> Address {
+    let account = Address::generate(env);
+    token::StellarAssetClient::new(env, token_address).mint(&account, &amount);
+    account
+}
+
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+fn persistent_ttl(env: &Env, client: &CrowdfundingContractClient, key: &StorageKey) -> u32 {
+    env.as_contract(&client.address, || env.storage().persistent().get_ttl(key))
+}
+
+fn instance_ttl(env: &Env, client: &CrowdfundingContractClient) -> u32 {
+    env.as_contract(&client.address, || env.storage().instance().get_ttl())
+}
+
+#[test]
+fn test_records_get_default_ttl_on_write() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+
+    assert_eq!(client.get_ttl_config(), TtlConfig::default());
+
+    let campaign_id = create_campaign(&env, &client, &token_address);
+    assert_eq!(
+        persistent_ttl(&env, &client, &StorageKey::Campaign(campaign_id)),
+        DEFAULT_TTL_EXTEND_TO
+    );
+    assert_eq!(instance_ttl(&env, &client), DEFAULT_TTL_EXTEND_TO);
+}
+
+#[test]
+fn test_set_ttl_config() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    client.set_ttl_config(&THRESHOLD, &EXTEND_TO);
+    assert_eq!(
+        client.get_ttl_config(),
+        TtlConfig {
+            threshold: THRESHOLD,
+            extend_to: EXTEND_TO,
+        }
+    );
+}
+
+#[test]
+fn test_set_ttl_config_rejects_invalid_values() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    assert_eq!(
+        client.try_set_ttl_config(&0, &EXTEND_TO),
+        Err(Ok(CrowdfundingError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_ttl_config(&EXTEND_TO, &EXTEND_TO),
+        Err(Ok(CrowdfundingError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_ttl_config(&THRESHOLD, &u32::MAX),
+        Err(Ok(CrowdfundingError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_set_ttl_config_requires_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.try_set_ttl_config(&THRESHOLD, &EXTEND_TO),
+        Err(Ok(CrowdfundingError::NotInitialized))
+    );
+}
+
+#[test]
+fn test_reading_a_campaign_extends_its_ttl() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    client.set_ttl_config(&THRESHOLD, &EXTEND_TO);
+    let campaign_id = create_campaign(&env, &client, &token_address);
+    let key = StorageKey::Campaign(campaign_id.clone());
+
+    advance_ledgers(&env, EXTEND_TO - THRESHOLD / 2);
+    assert_eq!(persistent_ttl(&env, &client, &key), THRESHOLD / 2);
+
+    client.get_campaign(&campaign_id);
+    assert_eq!(persistent_ttl(&env, &client, &key), EXTEND_TO);
+}
+
+#[test]
+fn test_ttl_is_not_extended_above_threshold() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    client.set_ttl_config(&THRESHOLD, &EXTEND_TO);
+    let campaign_id = create_campaign(&env, &client, &token_address);
+    let key = StorageKey::Campaign(campaign_id.clone());
+
+    advance_ledgers(&env, THRESHOLD / 2);
+    client.get_campaign(&campaign_id);
+    assert_eq!(
+        persistent_ttl(&env, &client, &key),
+        EXTEND_TO - THRESHOLD / 2
+    );
+}
+
+#[test]
+fn test_extend_campaign_ttl_covers_listed_donors() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    client.set_ttl_config(&THRESHOLD, &EXTEND_TO);
+    let campaign_id = create_campaign(&env, &client, &token_address);
+    let donor = funded(&env, &token_address, 500);
+    client.donate(&campaign_id, &donor, &token_address, &500);
+
+    advance_ledgers(&env, EXTEND_TO - THRESHOLD / 2);
+    client.extend_campaign_ttl(&campaign_id, &vec![&env, donor.clone()]);
+
+    for key in [
+        StorageKey::Campaign(campaign_id.clone()),
+        StorageKey::CampaignMetrics(campaign_id.clone()),
+        StorageKey::Contribution(campaign_id.clone(), donor.clone()),
+        StorageKey::CampaignDonor(campaign_id.clone(), donor.clone()),
+    ] {
+        assert_eq!(persistent_ttl(&env, &client, &key), EXTEND_TO);
+    }
+}
+
+#[test]
+fn test_extend_campaign_ttl_unknown_campaign() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    let result =
+        client.try_extend_campaign_ttl(&BytesN::from_array(&env, &[9u8; 32]), &Vec::new(&env));
+    assert_eq!(result, Err(Ok(CrowdfundingError::CampaignNotFound)));
+}
+
+#[test]
+fn test_extend_pool_ttl_covers_listed_contributors() {
+    let env = Env::default();
+    let (client, token_address) = setup(&env);
+    client.set_ttl_config(&THRESHOLD, &EXTEND_TO);
+    let pool_id = create_pool(&env, &client);
+    let contributor = funded(&env, &token_address, 300);
+    client.contribute(&pool_id, &contributor, &token_address, &300, &false);
+
+    advance_ledgers(&env, EXTEND_TO - THRESHOLD / 2);
+    client.extend_pool_ttl(&pool_id, &vec![&env, contributor.clone()]);
+
+    for key in [
+        StorageKey::Pool(pool_id),
+        StorageKey::PoolMetrics(pool_id),
+        StorageKey::PoolAssetTotals(pool_id),
+        StorageKey::PoolContribution(pool_id, contributor.clone(), token_address.clone()),
+    ] {
+        assert_eq!(persistent_ttl(&env, &client, &key), EXTEND_TO);
+    }
+}
+
+#[test]
+fn test_extend_pool_ttl_unknown_pool() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    let result = client.try_extend_pool_ttl(&42, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(CrowdfundingError::PoolNotFound)));
+}
+
+#[test]
+fn test_extend_instance_ttl() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    client.set_ttl_config(&THRESHOLD, &EXTEND_TO);
+
+    // Initialization extended the instance using the default policy.
+    let remaining = instance_ttl(&env, &client);
+    advance_ledgers(&env, remaining - THRESHOLD / 2);
+    assert_eq!(instance_ttl(&env, &client), THRESHOLD / 2);
+
+    client.extend_instance_ttl();
+    assert_eq!(instance_ttl(&env, &client), EXTEND_TO);
+}
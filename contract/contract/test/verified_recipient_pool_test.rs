@@ -0,0 +1,163 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, vec, Address, BytesN, Env, String, Vec};
+
+use crate::{
+    base::{errors::CrowdfundingError, types::PoolMetadata},
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
+
+struct TestContext<'a> {
+    client: CrowdfundingContractClient<'a>,
+    admin: Address,
+    token_address: Address,
+    signer: Address,
+    pool_id: u64,
+}
+
+fn setup_pool(env: &Env, verified_recipients_only: bool) -> TestContext<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.initialize(&admin, &token_address, &0);
+
+    let signer = Address::generate(env);
+    let metadata = PoolMetadata {
+        description: String::from_str(env, "Verified payouts only"),
+        external_url: String::from_str(env, ""),
+        image_hash: String::from_str(env, ""),
+    };
+    let pool_id = client.save_pool(
+        &String::from_str(env, "Verified Pool"),
+        &metadata,
+        &Address::generate(env),
+        &10_000i128,
+        &(env.ledger().timestamp() + 86400),
+        &Some(1u32),
+        &Some(vec![env, signer.clone()]),
+        &None::<Vec<Address>>,
+        &verified_recipients_only,
+    );
+
+    let contributor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_address).mint(&contributor, &1_000);
+    client.contribute(&pool_id, &contributor, &token_address, &1_000, &false);
+
+    TestContext {
+        client,
+        admin,
+        token_address,
+        signer,
+        pool_id,
+    }
+}
+
+fn verify(env: &Env, ctx: &TestContext, cause: &Address) {
+    ctx.client.verify_cause(
+        &ctx.admin,
+        cause,
+        &None::<u64>,
+        &BytesN::from_array(env, &[3u8; 32]),
+        &String::from_str(env, "Relief"),
+    );
+}
+
+#[test]
+fn test_unrestricted_pool_pays_any_recipient() {
+    let env = Env::default();
+    let ctx = setup_pool(&env, false);
+    let recipient = Address::generate(&env);
+
+    assert!(!ctx.client.is_pool_verified_recipients_only(&ctx.pool_id));
+    let id = ctx.client.propose_disbursement(
+        &ctx.pool_id,
+        &ctx.signer,
+        &recipient,
+        &ctx.token_address,
+        &1_000,
+    );
+    ctx.client
+        .execute_disbursement(&ctx.pool_id, &id, &ctx.signer);
+
+    assert_eq!(
+        token::Client::new(&env, &ctx.token_address).balance(&recipient),
+        1_000
+    );
+}
+
+#[test]
+fn test_restricted_pool_rejects_unverified_recipient() {
+    let env = Env::default();
+    let ctx = setup_pool(&env, true);
+
+    assert!(ctx.client.is_pool_verified_recipients_only(&ctx.pool_id));
+    let result = ctx.client.try_propose_disbursement(
+        &ctx.pool_id,
+        &ctx.signer,
+        &Address::generate(&env),
+        &ctx.token_address,
+        &1_000,
+    );
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+}
+
+#[test]
+fn test_restricted_pool_pays_verified_recipient() {
+    let env = Env::default();
+    let ctx = setup_pool(&env, true);
+    let cause = Address::generate(&env);
+    verify(&env, &ctx, &cause);
+
+    let id = ctx.client.propose_disbursement(
+        &ctx.pool_id,
+        &ctx.signer,
+        &cause,
+        &ctx.token_address,
+        &1_000,
+    );
+    ctx.client
+        .execute_disbursement(&ctx.pool_id, &id, &ctx.signer);
+
+    assert_eq!(
+        token::Client::new(&env, &ctx.token_address).balance(&cause),
+        1_000
+    );
+}
+
+#[test]
+fn test_verification_checked_again_at_execution() {
+    let env = Env::default();
+    let ctx = setup_pool(&env, true);
+    let cause = Address::generate(&env);
+    verify(&env, &ctx, &cause);
+
+    let id = ctx.client.propose_disbursement(
+        &ctx.pool_id,
+        &ctx.signer,
+        &cause,
+        &ctx.token_address,
+        &1_000,
+    );
+    ctx.client.revoke_cause(&ctx.admin, &cause);
+
+    let result = ctx
+        .client
+        .try_execute_disbursement(&ctx.pool_id, &id, &ctx.signer);
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+}
+
+#[test]
+fn test_verified_recipients_flag_nonexistent_pool() {
+    let env = Env::default();
+    let ctx = setup_pool(&env, false);
+
+    assert_eq!(
+        ctx.client.try_is_pool_verified_recipients_only(&999),
+        Err(Ok(CrowdfundingError::PoolNotFound))
+    );
+}
@@ -1,48 +1,72 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env, IntoVal};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN, Env, IntoVal, String,
+};
 
-use crate::crowdfunding::{CrowdfundingContract, CrowdfundingContractClient};
-use crate::interfaces::crowdfunding::CrowdfundingTrait;
+use crate::{
+    base::{errors::CrowdfundingError, types::StorageKey},
+    crowdfunding::{CrowdfundingContract, CrowdfundingContractClient},
+};
 
 fn create_client() -> (Env, CrowdfundingContractClient<'static>) {
     let env = Env::default();
     env.mock_all_auths();
-    let contract_id = env.register_contract(None, CrowdfundingContract);
+    let contract_id = env.register(CrowdfundingContract, ());
     let client = CrowdfundingContractClient::new(&env, &contract_id);
     (env, client)
 }
 
-#[test]
-fn test_verify_cause_success() {
+fn initialized_client() -> (Env, CrowdfundingContractClient<'static>, Address) {
     let (env, client) = create_client();
 
     let admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.initialize(&admin, &token, &1000);
+
+    (env, client, admin)
+}
+
+fn registration_ref(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[42u8; 32])
+}
+
+#[test]
+fn test_verify_cause_success() {
+    let (env, client, admin) = initialized_client();
     let cause = Address::generate(&env);
-    let token = Address::generate(&env);
-    let creation_fee = 1000;
+    let category = String::from_str(&env, "Education");
 
-    client.initialize(&admin, &token, &creation_fee);
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    client.verify_cause(
+        &admin,
+        &cause,
+        &None::<u64>,
+        &registration_ref(&env),
+        &category,
+    );
 
-    // Verify the cause
-    client.verify_cause(&cause);
+    assert!(client.is_cause_verified(&cause));
 
-    // Check if it is verified
-    assert_eq!(client.is_cause_verified(&cause), true);
+    let verification = client.get_cause_verification(&cause).unwrap();
+    assert_eq!(verification.verifier, admin);
+    assert_eq!(verification.verified_at, 500);
+    assert_eq!(verification.expires_at, None);
+    assert_eq!(verification.registration_ref, registration_ref(&env));
+    assert_eq!(verification.category, category);
 }
 
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
 fn test_verify_cause_unauthorized() {
-    let (env, client) = create_client();
+    let (env, client, admin) = initialized_client();
 
-    let admin = Address::generate(&env);
     let non_admin = Address::generate(&env);
     let cause = Address::generate(&env);
-    let token = Address::generate(&env);
-    let creation_fee = 1000;
-
-    client.initialize(&admin, &token, &creation_fee);
+    let category = String::from_str(&env, "Education");
 
     // Unmock auth to test failing auth
     env.mock_auths(&[soroban_sdk::testutils::MockAuth {
@@ -50,13 +74,26 @@ fn test_verify_cause_unauthorized() {
         invoke: &soroban_sdk::testutils::MockAuthInvoke {
             contract: &client.address,
             fn_name: "verify_cause",
-            args: (&cause,).into_val(&env),
+            args: (
+                &admin,
+                &cause,
+                None::<u64>,
+                registration_ref(&env),
+                &category,
+            )
+                .into_val(&env),
             sub_invokes: &[],
         },
     }]);
 
     // This should panic because non_admin is authenticating, not admin, but `verify_cause` requires admin auth.
-    client.verify_cause(&cause);
+    client.verify_cause(
+        &admin,
+        &cause,
+        &None::<u64>,
+        &registration_ref(&env),
+        &category,
+    );
 }
 
 #[test]
@@ -67,5 +104,402 @@ fn test_verify_cause_not_initialized() {
     let cause = Address::generate(&env);
 
     // Should panic with NotInitialized
-    client.verify_cause(&cause);
+    client.verify_cause(
+        &Address::generate(&env),
+        &cause,
+        &None::<u64>,
+        &registration_ref(&env),
+        &String::from_str(&env, ""),
+    );
+}
+
+#[test]
+fn test_verification_expires() {
+    let (env, client, admin) = initialized_client();
+    let cause = Address::generate(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.verify_cause(
+        &admin,
+        &cause,
+        &Some(2_000u64),
+        &registration_ref(&env),
+        &String::from_str(&env, "Health"),
+    );
+    assert!(client.is_cause_verified(&cause));
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert!(!client.is_cause_verified(&cause));
+    assert!(client.get_cause_verification(&cause).is_some());
+}
+
+#[test]
+fn test_attestations_keep_earliest_expiry() {
+    let (env, client, admin) = initialized_client();
+    let verifier = Address::generate(&env);
+    let cause = Address::generate(&env);
+    client.add_cause_verifier(&verifier);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let attest = |by: &Address, expires_at: Option<u64>| {
+        client.verify_cause(
+            by,
+            &cause,
+            &expires_at,
+            &registration_ref(&env),
+            &String::from_str(&env, "Health"),
+        );
+        client.get_cause_verification(&cause).unwrap().expires_at
+    };
+
+    assert_eq!(attest(&admin, Some(3_000)), Some(3_000));
+    assert_eq!(attest(&verifier, None), Some(3_000));
+    assert_eq!(attest(&verifier, Some(5_000)), Some(3_000));
+    assert_eq!(attest(&admin, Some(2_000)), Some(2_000));
+
+    // Once the recorded expiry has passed, a new attestation starts afresh
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert!(!client.is_cause_verified(&cause));
+    assert_eq!(attest(&verifier, None), None);
+    assert!(client.is_cause_verified(&cause));
+}
+
+#[test]
+fn test_verify_cause_rejects_past_expiry() {
+    let (env, client, admin) = initialized_client();
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let result = client.try_verify_cause(
+        &admin,
+        &Address::generate(&env),
+        &Some(1_000u64),
+        &registration_ref(&env),
+        &String::from_str(&env, "Health"),
+    );
+    assert_eq!(result, Err(Ok(CrowdfundingError::InvalidDeadline)));
+}
+
+#[test]
+fn test_revoke_cause() {
+    let (env, client, admin) = initialized_client();
+    let cause = Address::generate(&env);
+
+    client.verify_cause(
+        &admin,
+        &cause,
+        &None::<u64>,
+        &registration_ref(&env),
+        &String::from_str(&env, "Health"),
+    );
+    client.revoke_cause(&admin, &cause);
+
+    assert!(!client.is_cause_verified(&cause));
+    assert_eq!(client.get_cause_verification(&cause), None);
+    assert_eq!(
+        client.try_revoke_cause(&admin, &cause),
+        Err(Ok(CrowdfundingError::CauseNotFound))
+    );
+}
+
+#[test]
+fn test_delegated_verifier_can_verify_and_revoke() {
+    let (env, client, _) = initialized_client();
+    let verifier = Address::generate(&env);
+    let cause = Address::generate(&env);
+    let category = String::from_str(&env, "Health");
+
+    client.add_cause_verifier(&verifier);
+    assert_eq!(client.get_cause_verifiers(), vec![&env, verifier.clone()]);
+
+    client.verify_cause(
+        &verifier,
+        &cause,
+        &None::<u64>,
+        &registration_ref(&env),
+        &category,
+    );
+    assert!(client.is_cause_verified(&cause));
+    assert_eq!(
+        client.get_cause_verification(&cause).unwrap().verifier,
+        verifier
+    );
+    assert_eq!(
+        client.get_cause_attestations(&cause),
+        vec![&env, verifier.clone()]
+    );
+
+    client.revoke_cause(&verifier, &cause);
+    assert!(!client.is_cause_verified(&cause));
+    assert!(client.get_cause_attestations(&cause).is_empty());
+}
+
+#[test]
+fn test_non_verifier_cannot_verify() {
+    let (env, client, _) = initialized_client();
+
+    let outsider = Address::generate(&env);
+    let result = client.try_verify_cause(
+        &outsider,
+        &Address::generate(&env),
+        &None::<u64>,
+        &registration_ref(&env),
+        &String::from_str(&env, "Health"),
+    );
+    assert_eq!(result, Err(Ok(CrowdfundingError::Unauthorized)));
+}
+
+#[test]
+fn test_required_attestations() {
+    let (env, client, _) = initialized_client();
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let cause = Address::generate(&env);
+    let category = String::from_str(&env, "Health");
+
+    client.add_cause_verifier(&first);
+    client.add_cause_verifier(&second);
+    client.set_required_attestations(&2);
+    assert_eq!(client.get_required_attestations(), 2);
+
+    client.verify_cause(
+        &first,
+        &cause,
+        &None::<u64>,
+        &registration_ref(&env),
+        &category,
+    );
+    assert!(!client.is_cause_verified(&cause));
+
+    // Repeat attestations from the same verifier do not count twice
+    client.verify_cause(
+        &first,
+        &cause,
+        &None::<u64>,
+        &registration_ref(&env),
+        &category,
+    );
+    assert!(!client.is_cause_verified(&cause));
+
+    client.verify_cause(
+        &second,
+        &cause,
+        &None::<u64>,
+        &registration_ref(&env),
+        &category,
+    );
+    assert!(client.is_cause_verified(&cause));
+    assert_eq!(
+        client.get_cause_attestations(&cause),
+        vec![&env, first.clone(), second.clone()]
+    );
+
+    // Attestations from removed verifiers stop counting
+    client.remove_cause_verifier(&second);
+    assert!(!client.is_cause_verified(&cause));
+}
+
+#[test]
+fn test_expired_attestations_do_not_count_after_reverification() {
+    let (env, client, _) = initialized_client();
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let cause = Address::generate(&env);
+    let category = String::from_str(&env, "Health");
+
+    client.add_cause_verifier(&first);
+    client.add_cause_verifier(&second);
+    client.set_required_attestations(&2);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    for verifier in [&first, &second] {
+        client.verify_cause(
+            verifier,
+            &cause,
+            &Some(200u64),
+            &registration_ref(&env),
+            &category,
+        );
+    }
+    assert!(client.is_cause_verified(&cause));
+
+    // After expiry a single fresh attestation is not enough
+    env.ledger().with_mut(|li| li.timestamp = 300);
+    client.verify_cause(
+        &first,
+        &cause,
+        &None::<u64>,
+        &registration_ref(&env),
+        &category,
+    );
+    assert!(!client.is_cause_verified(&cause));
+    assert_eq!(
+        client.get_cause_attestations(&cause),
+        vec![&env, first.clone()]
+    );
+
+    client.verify_cause(
+        &second,
+        &cause,
+        &None::<u64>,
+        &registration_ref(&env),
+        &category,
+    );
+    assert!(client.is_cause_verified(&cause));
+}
+
+#[test]
+fn test_readded_verifier_must_attest_again() {
+    let (env, client, _) = initialized_client();
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let cause = Address::generate(&env);
+    let category = String::from_str(&env, "Health");
+
+    client.add_cause_verifier(&first);
+    client.add_cause_verifier(&second);
+    client.set_required_attestations(&2);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    for verifier in [&first, &second] {
+        client.verify_cause(
+            verifier,
+            &cause,
+            &None::<u64>,
+            &registration_ref(&env),
+            &category,
+        );
+    }
+    assert!(client.is_cause_verified(&cause));
+
+    client.set_required_attestations(&1);
+    client.remove_cause_verifier(&second);
+    client.set_required_attestations(&2);
+
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.add_cause_verifier(&second);
+    assert!(!client.is_cause_verified(&cause));
+
+    client.verify_cause(
+        &second,
+        &cause,
+        &None::<u64>,
+        &registration_ref(&env),
+        &category,
+    );
+    assert!(client.is_cause_verified(&cause));
+}
+
+#[test]
+fn test_verifier_management_errors() {
+    let (env, client, _) = initialized_client();
+    let verifier = Address::generate(&env);
+
+    client.add_cause_verifier(&verifier);
+    assert_eq!(
+        client.try_add_cause_verifier(&verifier),
+        Err(Ok(CrowdfundingError::SignerAlreadyExists))
+    );
+    assert_eq!(
+        client.try_remove_cause_verifier(&Address::generate(&env)),
+        Err(Ok(CrowdfundingError::SignerNotFound))
+    );
+    assert_eq!(
+        client.try_set_required_attestations(&0),
+        Err(Ok(CrowdfundingError::InvalidSignerCount))
+    );
+    assert_eq!(
+        client.try_set_required_attestations(&3),
+        Err(Ok(CrowdfundingError::InvalidSignerCount))
+    );
+
+    // Removing the verifier would leave only the admin to meet a threshold of 2
+    client.set_required_attestations(&2);
+    assert_eq!(
+        client.try_remove_cause_verifier(&verifier),
+        Err(Ok(CrowdfundingError::InvalidSignerCount))
+    );
+    client.set_required_attestations(&1);
+    client.remove_cause_verifier(&verifier);
+    assert!(client.get_cause_verifiers().is_empty());
+}
+
+fn set_legacy_verification(env: &Env, client: &CrowdfundingContractClient, cause: &Address) {
+    // Earlier versions stored a bare `true` in instance storage
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&StorageKey::VerifiedCause(cause.clone()), &true);
+    });
+}
+
+#[test]
+fn test_legacy_verification_is_converted() {
+    let (env, client, admin) = initialized_client();
+    let cause = Address::generate(&env);
+    set_legacy_verification(&env, &client, &cause);
+
+    assert!(client.is_cause_verified(&cause));
+    let verification = client.get_cause_verification(&cause).unwrap();
+    assert_eq!(verification.verifier, admin);
+    assert_eq!(verification.expires_at, None);
+    assert_eq!(client.get_cause_attestations(&cause), vec![&env, admin]);
+}
+
+#[test]
+fn test_legacy_verification_can_be_revoked() {
+    let (env, client, admin) = initialized_client();
+    let cause = Address::generate(&env);
+    set_legacy_verification(&env, &client, &cause);
+
+    client.revoke_cause(&admin, &cause);
+    assert!(!client.is_cause_verified(&cause));
+    assert_eq!(client.get_cause_verification(&cause), None);
+}
+
+#[test]
+fn test_legacy_verification_replaced_by_new_attestation() {
+    let (env, client, admin) = initialized_client();
+    let verifier = Address::generate(&env);
+    let cause = Address::generate(&env);
+    client.add_cause_verifier(&verifier);
+    set_legacy_verification(&env, &client, &cause);
+
+    client.verify_cause(
+        &verifier,
+        &cause,
+        &None::<u64>,
+        &registration_ref(&env),
+        &String::from_str(&env, "Health"),
+    );
+    assert_eq!(
+        client.get_cause_verification(&cause).unwrap().verifier,
+        verifier
+    );
+    assert_eq!(
+        client.get_cause_attestations(&cause),
+        vec![&env, admin, verifier]
+    );
+    env.as_contract(&client.address, || {
+        assert!(!env
+            .storage()
+            .instance()
+            .has(&StorageKey::VerifiedCause(cause.clone())));
+    });
+}
+
+#[test]
+fn test_legacy_verification_reverified_by_admin() {
+    let (env, client, admin) = initialized_client();
+    let cause = Address::generate(&env);
+    set_legacy_verification(&env, &client, &cause);
+
+    client.verify_cause(
+        &admin,
+        &cause,
+        &None::<u64>,
+        &registration_ref(&env),
+        &String::from_str(&env, "Health"),
+    );
+    assert!(client.is_cause_verified(&cause));
+    assert_eq!(client.get_cause_attestations(&cause), vec![&env, admin]);
 }